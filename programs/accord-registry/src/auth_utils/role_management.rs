@@ -1,5 +1,10 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::models::*;
+use crate::instructions::registry_config::apply_config_update;
+use crate::instructions::verifier_stake::slash_stake;
+use crate::instructions::disputes::resolve_dispute;
+use crate::instructions::escrow::EscrowTokenAccounts;
 
 // ========================================
 // ROLE MANAGEMENT INSTRUCTIONS
@@ -217,6 +222,73 @@ pub struct ExecuteProposal<'info> {
     pub executor_account: Account<'info, UserAccount>,
     
     pub executor: Signer<'info>,
+
+    // Required for UpdateRegistry proposals
+    #[account(
+        mut,
+        seeds = [b"registry_config"],
+        bump = registry_config.bump
+    )]
+    pub registry_config: Option<Account<'info, RegistryConfig>>,
+
+    // Required for UpdateRegistry proposals that change h3_resolution
+    #[account(
+        seeds = [b"double_counting_registry"],
+        bump = double_counting_registry.bump
+    )]
+    pub double_counting_registry: Option<Account<'info, DoubleCountingRegistry>>,
+
+    // Required for SlashVerifier proposals; the proposal target is the verifier authority
+    #[account(
+        mut,
+        seeds = [b"verifier_stake", proposal.target.as_ref()],
+        bump = verifier_stake.bump
+    )]
+    pub verifier_stake: Option<Account<'info, VerifierStake>>,
+
+    #[account(
+        mut,
+        seeds = [b"verifier", proposal.target.as_ref()],
+        bump
+    )]
+    pub verifier: Option<Account<'info, VerificationNode>>,
+
+    // Treasury receiving slashed stake
+    #[account(
+        mut,
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Option<Account<'info, GlobalRegistry>>,
+
+    // Required for ResolveDispute proposals; the proposal target is the dispute
+    #[account(
+        mut,
+        address = proposal.target
+    )]
+    pub dispute: Option<Account<'info, VerificationDispute>>,
+
+    #[account(mut)]
+    pub project: Option<Account<'info, Project>>,
+
+    /// CHECK: Refund destination, checked against the dispute
+    #[account(mut)]
+    pub challenger: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Escrowed fee refund destination on overturn, checked against the project
+    #[account(mut)]
+    pub project_owner: Option<UncheckedAccount<'info>>,
+
+    // Required to refund a stablecoin escrow on overturn; checked against the project
+    #[account(mut)]
+    pub escrow_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub escrow_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub owner_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 }
 
 // ========================================
@@ -421,6 +493,65 @@ pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
         ErrorCode::InsufficientApprovals
     );
     
+    if proposal.proposal_type == ProposalType::UpdateRegistry {
+        let registry_config = ctx
+            .accounts
+            .registry_config
+            .as_mut()
+            .ok_or(ErrorCode::MissingRegistryConfig)?;
+        let update = RegistryConfigUpdate::try_from_slice(&proposal.data)
+            .map_err(|_| ErrorCode::InvalidProposalData)?;
+        apply_config_update(
+            registry_config,
+            ctx.accounts.double_counting_registry.as_deref(),
+            update,
+            proposal.proposal_id,
+        )?;
+    }
+
+    if proposal.proposal_type == ProposalType::SlashVerifier {
+        let (Some(verifier_stake), Some(verifier), Some(registry)) = (
+            ctx.accounts.verifier_stake.as_mut(),
+            ctx.accounts.verifier.as_mut(),
+            ctx.accounts.registry.as_ref(),
+        ) else {
+            return err!(ErrorCode::MissingSlashAccounts);
+        };
+        let slash = VerifierSlash::try_from_slice(&proposal.data)
+            .map_err(|_| ErrorCode::InvalidProposalData)?;
+        slash_stake(verifier_stake, verifier, &registry.to_account_info(), &slash)?;
+    }
+
+    if proposal.proposal_type == ProposalType::ResolveDispute {
+        let (Some(dispute), Some(project), Some(challenger), Some(registry)) = (
+            ctx.accounts.dispute.as_mut(),
+            ctx.accounts.project.as_mut(),
+            ctx.accounts.challenger.as_ref(),
+            ctx.accounts.registry.as_ref(),
+        ) else {
+            return err!(ErrorCode::MissingDisputeAccounts);
+        };
+        let resolution = DisputeResolution::try_from_slice(&proposal.data)
+            .map_err(|_| ErrorCode::InvalidProposalData)?;
+        let tokens = EscrowTokenAccounts::from_optional(
+            &ctx.accounts.escrow_vault,
+            &ctx.accounts.escrow_mint,
+            &ctx.accounts.owner_token_account,
+            &ctx.accounts.token_program,
+        );
+        let owner = ctx.accounts.project_owner.as_ref().map(|o| o.to_account_info());
+        resolve_dispute(
+            dispute,
+            project,
+            &challenger.to_account_info(),
+            &registry.to_account_info(),
+            owner.as_ref(),
+            tokens,
+            resolution.uphold,
+            ctx.accounts.executor.key(),
+        )?;
+    }
+    
     proposal.executed = true;
     
     msg!("Proposal {} executed", proposal.proposal_id);
@@ -494,4 +625,16 @@ pub enum ErrorCode {
     
     #[msg("User account is not active")]
     UserNotActive,
+    
+    #[msg("Registry config account is required for this proposal")]
+    MissingRegistryConfig,
+    
+    #[msg("Proposal data could not be decoded")]
+    InvalidProposalData,
+    
+    #[msg("Verifier stake, verifier and registry accounts are required to slash")]
+    MissingSlashAccounts,
+    
+    #[msg("Dispute, project, challenger and registry accounts are required to resolve")]
+    MissingDisputeAccounts,
 }
\ No newline at end of file
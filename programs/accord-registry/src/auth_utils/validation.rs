@@ -1,6 +1,94 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::get_instruction_relative;
+use solana_sdk_ids::ed25519_program;
+use solana_sha256_hasher::hash;
+use crate::models::GeoLocation;
+use crate::instructions::errors::ErrorCode;
 
 pub fn validate_authority(authority: &Signer) -> Result<()> {
     msg!("Validating authority: {:?}", authority.key());
     Ok(())
-}
\ No newline at end of file
+}
+
+// Layout of the native ed25519 program instruction data
+const ED25519_HEADER_LEN: usize = 2;
+const ED25519_OFFSETS_LEN: usize = 14;
+const ED25519_PUBKEY_LEN: usize = 32;
+const ED25519_SIGNATURE_LEN: usize = 64;
+
+/// Message the government authority signs to attest a registry ID:
+/// `sha256(borsh(ccts_registry_id, owner, location))`.
+pub fn compliance_id_message(
+    ccts_registry_id: &str,
+    owner: &Pubkey,
+    location: &GeoLocation,
+) -> Result<[u8; 32]> {
+    let payload = (ccts_registry_id.to_string(), *owner, location.clone())
+        .try_to_vec()
+        .map_err(|_| ErrorCode::InvalidComplianceSignature)?;
+    Ok(hash(&payload).to_bytes())
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16> {
+    let bytes = data
+        .get(offset..offset + 2)
+        .ok_or(ErrorCode::InvalidComplianceSignature)?;
+    Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_slice(data: &[u8], offset: u16, len: usize) -> Result<&[u8]> {
+    let start = offset as usize;
+    Ok(data
+        .get(start..start + len)
+        .ok_or(ErrorCode::InvalidComplianceSignature)?)
+}
+
+/// Checks that the instruction immediately before the current one is a native
+/// ed25519 verification of `expected_signature` by `expected_signer` over
+/// `expected_message`.
+///
+/// The ed25519 program aborts the transaction if the signature is invalid, so
+/// by the time we run, only the signer, message and signature need matching.
+pub fn verify_ed25519_instruction(
+    instructions_sysvar: &AccountInfo,
+    expected_signer: &Pubkey,
+    expected_message: &[u8],
+    expected_signature: &[u8],
+) -> Result<()> {
+    let ix = get_instruction_relative(-1, instructions_sysvar)
+        .map_err(|_| ErrorCode::MissingComplianceSignature)?;
+
+    require_keys_eq!(ix.program_id, ed25519_program::ID, ErrorCode::MissingComplianceSignature);
+    require!(ix.accounts.is_empty(), ErrorCode::InvalidComplianceSignature);
+
+    let data = &ix.data;
+    require!(
+        data.len() >= ED25519_HEADER_LEN + ED25519_OFFSETS_LEN && data[0] == 1,
+        ErrorCode::InvalidComplianceSignature
+    );
+
+    let offsets = ED25519_HEADER_LEN;
+    let signature_offset = read_u16(data, offsets)?;
+    let signature_ix_index = read_u16(data, offsets + 2)?;
+    let pubkey_offset = read_u16(data, offsets + 4)?;
+    let pubkey_ix_index = read_u16(data, offsets + 6)?;
+    let message_offset = read_u16(data, offsets + 8)?;
+    let message_size = read_u16(data, offsets + 10)?;
+    let message_ix_index = read_u16(data, offsets + 12)?;
+
+    // All data must live inside the ed25519 instruction itself
+    require!(
+        signature_ix_index == u16::MAX && pubkey_ix_index == u16::MAX && message_ix_index == u16::MAX,
+        ErrorCode::InvalidComplianceSignature
+    );
+
+    let signer = read_slice(data, pubkey_offset, ED25519_PUBKEY_LEN)?;
+    let signature = read_slice(data, signature_offset, ED25519_SIGNATURE_LEN)?;
+    let message = read_slice(data, message_offset, message_size as usize)?;
+
+    require!(signer == expected_signer.as_ref(), ErrorCode::InvalidComplianceSignature);
+    require!(message == expected_message, ErrorCode::InvalidComplianceSignature);
+    require!(signature == expected_signature, ErrorCode::InvalidComplianceSignature);
+
+    Ok(())
+}
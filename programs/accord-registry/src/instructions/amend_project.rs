@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use crate::models::*;
+use crate::instructions::{contexts::*, errors::ErrorCode};
+use crate::instructions::double_counting::update_cells;
+use crate::instructions::lifecycle::transition_status;
+use crate::instructions::register_project::project_h3_cells;
+use crate::auth_utils::validation::{compliance_id_message, verify_ed25519_instruction};
+
+pub fn amend_project<'info>(
+    ctx: Context<'_, '_, '_, 'info, AmendProject<'info>>,
+    amendment: ProjectAmendmentData,
+) -> Result<()> {
+    let project = &mut ctx.accounts.project;
+    let history = &mut ctx.accounts.project_version;
+    let owner = ctx.accounts.owner.key();
+
+    require!(
+        amendment.ipfs_cid.is_some()
+            || amendment.carbon_tons_estimated.is_some()
+            || amendment.location.is_some()
+            || amendment.area_hectares.is_some()
+            || amendment.price_per_ton.is_some(),
+        ErrorCode::EmptyAmendment
+    );
+
+    let old_ipfs_cid = project.ipfs_cid.clone();
+    let old_carbon_tons_estimated = project.carbon_tons_estimated;
+    let previous_status = project.verification_status.clone();
+
+    // ========================================
+    // PDD & Estimates
+    // ========================================
+    if let Some(ipfs_cid) = amendment.ipfs_cid {
+        require!(
+            !ipfs_cid.is_empty() && ipfs_cid.len() <= Project::CID_LEN,
+            ErrorCode::InvalidIpfsCid
+        );
+        project.ipfs_cid = ipfs_cid;
+    }
+
+    if let Some(carbon_tons_estimated) = amendment.carbon_tons_estimated {
+        require!(carbon_tons_estimated > 0, ErrorCode::InvalidCarbonMeasurement);
+        // Once a verifier has set the tonnage only re-verification may change it
+        require!(project.verification_expires_at == 0, ErrorCode::VerifiedTonnageLocked);
+        project.carbon_tons_estimated = carbon_tons_estimated;
+        project.available_quantity = carbon_tons_estimated;
+    }
+
+    if let Some(area_hectares) = amendment.area_hectares {
+        project.area_hectares = area_hectares;
+    }
+
+    if let Some(price_per_ton) = amendment.price_per_ton {
+        project.price_per_ton = price_per_ton;
+    }
+
+    // ========================================
+    // Location: needs a fresh government attestation and moves the H3 cells
+    // ========================================
+    let location_changed = amendment.location.is_some();
+    if let Some(location) = amendment.location {
+        let attestation = compliance_id_message(&project.compliance.ccts_registry_id, &owner, &location)?;
+        verify_ed25519_instruction(
+            &ctx.accounts.instructions_sysvar.to_account_info(),
+            &ctx.accounts.registry.government_authority,
+            &attestation,
+            &amendment.compliance_id_signature,
+        )?;
+
+        // Old cells are released at the resolution they were reserved at
+        let h3_resolution = ctx.accounts.registry_config.h3_resolution;
+        let old_cells = project_h3_cells(&project.location, project.h3_resolution)?;
+        let new_cells = project_h3_cells(&location, h3_resolution)?;
+        update_cells(
+            ctx.program_id,
+            &mut ctx.accounts.double_counting_registry,
+            ctx.remaining_accounts,
+            &ctx.accounts.owner.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &old_cells,
+            &new_cells,
+        )?;
+
+        msg!("📍 Location amended: {} -> {} H3 cells", old_cells.len(), new_cells.len());
+        project.location = location;
+        project.h3_resolution = h3_resolution;
+    }
+
+    // Verified results no longer match the amended PDD
+    if matches!(
+        project.verification_status,
+        VerificationStatus::Verified | VerificationStatus::Monitoring
+    ) {
+        transition_status(project, VerificationStatus::UnderReview, owner)?;
+    }
+
+    project.version += 1;
+
+    // ========================================
+    // Record Version History
+    // ========================================
+    history.project = project.key();
+    history.version = project.version;
+    history.old_ipfs_cid = old_ipfs_cid;
+    history.new_ipfs_cid = project.ipfs_cid.clone();
+    history.old_carbon_tons_estimated = old_carbon_tons_estimated;
+    history.new_carbon_tons_estimated = project.carbon_tons_estimated;
+    history.location_changed = location_changed;
+    history.previous_status = previous_status;
+    history.amended_by = owner;
+    history.amended_at = Clock::get()?.unix_timestamp;
+    history.bump = ctx.bumps.project_version;
+
+    msg!("✏️ Project {} amended to version {}", project.project_id, project.version);
+
+    Ok(())
+}
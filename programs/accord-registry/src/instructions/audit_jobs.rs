@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::models::*;
+use crate::instructions::{
+    contexts::*,
+    errors::ErrorCode,
+    lifecycle::transition_status,
+    verifier_stake::move_lamports,
+};
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// Publishes an audit job for a project awaiting audit.
+pub fn post_audit_job(ctx: Context<PostAuditJob>, fee_budget: u64) -> Result<()> {
+    let project = &mut ctx.accounts.project;
+    let job = &mut ctx.accounts.audit_job;
+
+    require!(
+        project.verification_status == VerificationStatus::AwaitingAudit,
+        ErrorCode::InvalidStatusTransition
+    );
+    require!(project.escrow_mint.is_none(), ErrorCode::UnreleasedVerificationFee);
+    require!(
+        fee_budget >= ctx.accounts.registry_config.min_verification_fee,
+        ErrorCode::InsufficientVerificationFee
+    );
+
+    job.project = project.key();
+    job.index = project.audit_jobs;
+    job.fee_budget = fee_budget;
+    job.status = AuditJobStatus::Open;
+    job.bid_count = 0;
+    job.accepted_bid = None;
+    job.created_at = Clock::get()?.unix_timestamp;
+    job.bump = ctx.bumps.audit_job;
+
+    project.audit_jobs += 1;
+
+    msg!("📋 Audit job {} posted for {} with budget {} lamports", job.index, project.project_id, fee_budget);
+    Ok(())
+}
+
+/// Bids on an open audit job. Only active verifiers specialized in the
+/// project's sector, and with enough stake, may bid.
+pub fn submit_audit_bid(ctx: Context<SubmitAuditBid>, price: u64, timeline_days: u16) -> Result<()> {
+    let project = &ctx.accounts.project;
+    let job = &mut ctx.accounts.audit_job;
+    let verifier = &ctx.accounts.verifier;
+    let config = &ctx.accounts.registry_config;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(job.status == AuditJobStatus::Open, ErrorCode::AuditJobNotOpen);
+    require!(verifier.is_active_at(now), ErrorCode::VerifierNotActive);
+    require!(
+        verifier.is_specialized_in(&project.project_sector),
+        ErrorCode::VerifierNotSpecialized
+    );
+    require!(
+        price >= config.min_verification_fee && price <= job.fee_budget,
+        ErrorCode::InvalidAuditBid
+    );
+    require!(
+        timeline_days > 0 && (timeline_days as i64) * SECONDS_PER_DAY <= config.audit_timeout,
+        ErrorCode::InvalidAuditBid
+    );
+
+    let bid = &mut ctx.accounts.audit_bid;
+    bid.audit_job = job.key();
+    bid.verifier = ctx.accounts.verifier_authority.key();
+    bid.price = price;
+    bid.timeline_days = timeline_days;
+    bid.submitted_at = now;
+    bid.bump = ctx.bumps.audit_bid;
+
+    job.bid_count += 1;
+
+    msg!("🙋 Verifier {} bid {} lamports / {} days on job {}", bid.verifier, price, timeline_days, job.index);
+    Ok(())
+}
+
+/// Awards the job to a bid: assigns the verifier and tops up (or refunds)
+/// the project's escrow so it holds exactly the bid price. The bidder must
+/// still be active, specialized and staked when the bid is accepted.
+pub fn accept_audit_bid(ctx: Context<AcceptAuditBid>) -> Result<()> {
+    let bid = &ctx.accounts.audit_bid;
+    let job = &mut ctx.accounts.audit_job;
+    let verifier = &ctx.accounts.verifier;
+    let project = &mut ctx.accounts.project;
+    let owner = &ctx.accounts.owner;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(job.status == AuditJobStatus::Open, ErrorCode::AuditJobNotOpen);
+    require!(project.escrow_mint.is_none(), ErrorCode::UnreleasedVerificationFee);
+    require!(verifier.is_active_at(now), ErrorCode::VerifierNotActive);
+    require!(
+        verifier.is_specialized_in(&project.project_sector),
+        ErrorCode::VerifierNotSpecialized
+    );
+
+    let escrowed = project.verification_fee_lamports;
+    if bid.price > escrowed {
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: owner.to_account_info(),
+                to: project.to_account_info(),
+            },
+        );
+        system_program::transfer(cpi_context, bid.price - escrowed)?;
+    } else if bid.price < escrowed {
+        move_lamports(&project.to_account_info(), &owner.to_account_info(), escrowed - bid.price)?;
+    }
+
+    project.verifier = Some(bid.verifier);
+    project.verification_fee_lamports = bid.price;
+    project.audit_escrow_balance = bid.price;
+    // The verifier committed to a timeline (bounded by the audit timeout when bidding)
+    project.audit_deadline = now + bid.timeline_days as i64 * SECONDS_PER_DAY;
+    transition_status(project, VerificationStatus::AwaitingAudit, owner.key())?;
+
+    job.status = AuditJobStatus::Awarded;
+    job.accepted_bid = Some(bid.key());
+
+    msg!("🤝 Job {} awarded to verifier {}", job.index, bid.verifier);
+    msg!("Verification Escrow Funded: {} lamports", bid.price);
+    Ok(())
+}
+
+pub fn cancel_audit_job(ctx: Context<CancelAuditJob>) -> Result<()> {
+    let job = &mut ctx.accounts.audit_job;
+    require!(job.status == AuditJobStatus::Open, ErrorCode::AuditJobNotOpen);
+
+    job.status = AuditJobStatus::Cancelled;
+
+    msg!("Audit job {} cancelled", job.index);
+    Ok(())
+}
+
+/// Closes a bid, returning its rent to the verifier.
+pub fn withdraw_audit_bid(ctx: Context<WithdrawAuditBid>) -> Result<()> {
+    let job = &mut ctx.accounts.audit_job;
+    job.bid_count = job.bid_count.saturating_sub(1);
+
+    msg!("Bid on job {} withdrawn by {}", job.index, ctx.accounts.verifier_authority.key());
+    Ok(())
+}
@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Burn, MintTo, TransferChecked};
+use crate::models::*;
+use crate::instructions::{
+    contexts::*,
+    credit_mints::{cancel_buffer_serials, check_credit_recipient, credit_scale, thaw_if_frozen},
+    errors::ErrorCode,
+    registry_config::MAX_BUFFER_BPS,
+};
+
+/// Base units withheld into the buffer pool when `amount` is issued to
+/// recipients. Rounded down to whole tonnes so buffer credits keep serials.
+pub(crate) fn buffer_contribution(
+    config: &RegistryConfig,
+    project: &Project,
+    amount: u64,
+    scale: u64,
+) -> Result<u64> {
+    let tonnes = (amount / scale) as u128 * config.buffer_bps_for(project) as u128 / 10_000;
+    let buffer = u64::try_from(tonnes)
+        .ok()
+        .and_then(|tonnes| tonnes.checked_mul(scale))
+        .ok_or(ErrorCode::MathOverflow)?;
+    Ok(buffer)
+}
+
+/// Mints a buffer contribution into the mint's registry-owned buffer vault.
+/// Its permanence period is tracked on the issuance's IssuanceRecord.
+pub(crate) fn deposit_buffer<'info>(
+    info: &mut CreditMintInfo,
+    accounts: MintTo<'info>,
+    token_program: AccountInfo<'info>,
+    registry_bump: u8,
+    buffer: u64,
+) -> Result<()> {
+    if buffer == 0 {
+        return Ok(());
+    }
+
+    let seeds = &[b"registry_v3".as_ref(), &[registry_bump]];
+    let signer = &[&seeds[..]];
+    token_interface::mint_to(CpiContext::new_with_signer(token_program, accounts, signer), buffer)?;
+    info.buffer_held += buffer;
+
+    msg!("🛡️ Withheld {} into the buffer pool for {}", buffer, info.project_id);
+    Ok(())
+}
+
+/// Sets a project's risk-assessed buffer rate, or clears it to fall back to
+/// the sector default. Registry admin only.
+pub fn set_project_buffer_rate(ctx: Context<SetProjectBufferRate>, buffer_bps: Option<u16>) -> Result<()> {
+    if let Some(bps) = buffer_bps {
+        require!(bps <= MAX_BUFFER_BPS, ErrorCode::InvalidBufferRate);
+    }
+    let project = &mut ctx.accounts.project;
+    project.risk_buffer_bps = buffer_bps;
+
+    msg!("🛡️ Buffer rate for {} set to {:?} bps", project.project_id, buffer_bps);
+    Ok(())
+}
+
+/// Burns buffer credits withheld by one issuance to cover a reported reversal.
+/// The cancelled tonnes come out of that issuance's own buffer serials.
+pub fn cancel_buffer_credits(
+    ctx: Context<CancelBufferCredits>,
+    amount: u64,
+    evidence_cid: String,
+) -> Result<()> {
+    require!(
+        !evidence_cid.is_empty() && evidence_cid.len() <= Project::CID_LEN,
+        ErrorCode::InvalidIpfsCid
+    );
+    let record = &mut ctx.accounts.issuance_record;
+    let info = &mut ctx.accounts.credit_mint_info;
+    require!(
+        amount > 0 && amount <= record.buffer_held && amount <= info.buffer_held,
+        ErrorCode::InsufficientBufferCredits
+    );
+
+    let seeds = &[b"registry_v3".as_ref(), &[ctx.accounts.registry.bump]];
+    let signer = &[&seeds[..]];
+    token_interface::burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.credit_mint.to_account_info(),
+                from: ctx.accounts.buffer_vault.to_account_info(),
+                authority: ctx.accounts.registry.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+    )?;
+
+    let scale = credit_scale(ctx.accounts.credit_mint.decimals)?;
+    let (serial_start, serial_end) = cancel_buffer_serials(info, record, amount, scale)?;
+    info.buffer_held -= amount;
+
+    msg!("🔥 Reversal on {}: {} buffer credits cancelled", info.project_id, amount);
+    msg!("🔢 Serials {}-{:09}..{:09} cancelled", info.serial_prefix, serial_start, serial_end);
+    msg!("Evidence: {}", evidence_cid);
+    Ok(())
+}
+
+/// Returns an issuance's remaining buffer credits to the project owner once
+/// that issuance's permanence period has ended. Domestic-only credits are
+/// subject to the same jurisdiction check as any other transfer.
+pub fn release_buffer_credits(ctx: Context<ReleaseBufferCredits>) -> Result<()> {
+    check_credit_recipient(&ctx.accounts.credit_mint_info, ctx.accounts.owner_jurisdiction.as_ref())?;
+    if !ctx.accounts.credit_mint_info.export_authorized {
+        thaw_if_frozen(
+            &ctx.accounts.owner_token_account.to_account_info(),
+            &ctx.accounts.credit_mint.to_account_info(),
+            &ctx.accounts.registry.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.registry.bump,
+        )?;
+    }
+
+    let project = &ctx.accounts.project;
+    let record = &mut ctx.accounts.issuance_record;
+    let info = &mut ctx.accounts.credit_mint_info;
+    require!(
+        Clock::get()?.unix_timestamp >= record.permanence_ends_at,
+        ErrorCode::PermanencePeriodActive
+    );
+    let amount = record.buffer_held.min(info.buffer_held);
+    require!(amount > 0, ErrorCode::InsufficientBufferCredits);
+
+    let seeds = &[b"registry_v3".as_ref(), &[ctx.accounts.registry.bump]];
+    let signer = &[&seeds[..]];
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.buffer_vault.to_account_info(),
+                mint: ctx.accounts.credit_mint.to_account_info(),
+                to: ctx.accounts.owner_token_account.to_account_info(),
+                authority: ctx.accounts.registry.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+        ctx.accounts.credit_mint.decimals,
+    )?;
+    info.buffer_held -= amount;
+    record.buffer_held = 0;
+
+    msg!("🛡️ Released {} buffer credits of issuance {} to {}", amount, record.index, project.owner);
+    Ok(())
+}
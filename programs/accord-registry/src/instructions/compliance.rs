@@ -5,7 +5,7 @@ use crate::models::*;
 pub struct ApproveCompliance<'info> {
     #[account(
         mut,
-        seeds = [b"project", project.owner.as_ref(), project.project_id.as_bytes()],
+        seeds = [b"project", project.project_id.as_bytes()],
         bump = project.bump
     )]
     pub project: Account<'info, Project>,
@@ -49,8 +49,61 @@ pub fn approve_project_compliance(
     Ok(())
 }
 
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct SetParticipantJurisdiction<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ParticipantJurisdiction::LEN,
+        seeds = [b"jurisdiction", wallet.as_ref()],
+        bump
+    )]
+    pub jurisdiction: Account<'info, ParticipantJurisdiction>,
+
+    // The authority attesting residency (Government Agency)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"user", authority.key().as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.role == UserRole::Government || 
+                     user_account.role == UserRole::Admin @ ErrorCode::UnauthorizedGovernment
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Attests the country a wallet is resident in. Domestic-only credits can
+/// only be delivered to wallets resident in the project's host country.
+pub fn set_participant_jurisdiction(
+    ctx: Context<SetParticipantJurisdiction>,
+    wallet: Pubkey,
+    country_code: String,
+) -> Result<()> {
+    require!(
+        !country_code.is_empty() && country_code.len() <= ParticipantJurisdiction::MAX_COUNTRY_CODE_LEN,
+        ErrorCode::InvalidCountryCode
+    );
+
+    let jurisdiction = &mut ctx.accounts.jurisdiction;
+    jurisdiction.wallet = wallet;
+    jurisdiction.country_code = country_code;
+    jurisdiction.attested_by = ctx.accounts.authority.key();
+    jurisdiction.attested_at = Clock::get()?.unix_timestamp;
+    jurisdiction.bump = ctx.bumps.jurisdiction;
+
+    msg!("Jurisdiction Attested: {} resident in {}", wallet, jurisdiction.country_code);
+
+    Ok(())
+}
+
 #[error_code]
 pub enum ErrorCode {
     #[msg("Unauthorized: Only Government role can approve compliance")]
     UnauthorizedGovernment,
+    #[msg("Country code must be 1-4 characters")]
+    InvalidCountryCode,
 }
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
 use anchor_spl::{
     token_interface::{Mint, TokenAccount, TokenInterface},
     associated_token::AssociatedToken,
@@ -34,6 +35,33 @@ pub struct InitializeRegistry<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
+#[derive(Accounts)]
+pub struct InitializeRegistryConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + RegistryConfig::LEN,
+        seeds = [b"registry_config"],
+        bump
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump,
+        has_one = admin,
+        has_one = carbon_token_mint
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    pub carbon_token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
 // Account validation for the `register_project` instruction
 #[derive(Accounts)]
 #[instruction(project_data: ProjectRegistrationData)]
@@ -42,11 +70,20 @@ pub struct RegisterProject<'info> {
         init,
         payer = project_owner,
         space = 8 + Project::LEN,
-        seeds = [b"project", project_owner.key().as_ref(), project_data.project_id.as_bytes()],
+        seeds = [b"project", project_data.project_id.as_bytes()],
         bump
     )]
     pub project: Account<'info, Project>,
 
+    #[account(
+        init_if_needed,
+        payer = project_owner,
+        space = 8 + RegistryIdRecord::LEN,
+        seeds = [b"registry_id", project_data.project_id.as_bytes()],
+        bump
+    )]
+    pub registry_id_record: Account<'info, RegistryIdRecord>,
+
     #[account(
         mut,
         seeds = [b"registry_v3"],
@@ -54,6 +91,12 @@ pub struct RegisterProject<'info> {
     )]
     pub registry: Account<'info, GlobalRegistry>,
 
+    #[account(
+        seeds = [b"registry_config"],
+        bump = registry_config.bump
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
     #[account(mut)]
     pub project_owner: Signer<'info>,
 
@@ -72,279 +115,1654 @@ pub struct RegisterProject<'info> {
     )]
     pub double_counting_registry: Account<'info, DoubleCountingRegistry>,
 
+    /// CHECK: Instructions sysvar, used to read the preceding ed25519 verification
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AmendProject<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.project_id.as_bytes()],
+        bump = project.bump,
+        has_one = owner
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + ProjectVersion::LEN,
+        seeds = [b"project_version", project.key().as_ref(), project.generation.to_le_bytes().as_ref(), (project.version + 1).to_le_bytes().as_ref()],
+        bump
+    )]
+    pub project_version: Account<'info, ProjectVersion>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    #[account(
+        seeds = [b"registry_config"],
+        bump = registry_config.bump
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"double_counting_registry"],
+        bump = double_counting_registry.bump
+    )]
+    pub double_counting_registry: Account<'info, DoubleCountingRegistry>,
+
+    /// CHECK: Instructions sysvar, used to read the preceding ed25519 verification
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawProject<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.project_id.as_bytes()],
+        bump = project.bump,
+        has_one = owner,
+        close = owner
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    #[account(
+        mut,
+        seeds = [b"double_counting_registry"],
+        bump = double_counting_registry.bump
+    )]
+    pub double_counting_registry: Account<'info, DoubleCountingRegistry>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    // Required when the escrow is held in a stablecoin; the vault is refunded and closed
+    #[account(
+        mut,
+        seeds = [b"escrow_vault", project.key().as_ref()],
+        bump
+    )]
+    pub escrow_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub escrow_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = owner_token_account.owner == owner.key() @ ErrorCode::MissingEscrowTokenAccounts
+    )]
+    pub owner_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeProjectTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.project_id.as_bytes()],
+        bump = project.bump,
+        has_one = owner
+    )]
+    pub project: Account<'info, Project>,
+
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptProjectTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.project_id.as_bytes()],
+        bump = project.bump,
+        constraint = project.pending_owner == Some(new_owner.key()) @ ErrorCode::NoPendingOwnershipTransfer
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        init,
+        payer = new_owner,
+        space = 8 + ProjectOwnershipRecord::LEN,
+        seeds = [b"ownership_record", project.key().as_ref(), project.generation.to_le_bytes().as_ref(), project.ownership_transfers.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub ownership_record: Account<'info, ProjectOwnershipRecord>,
+
+    #[account(mut)]
+    pub new_owner: Signer<'info>,
+
+    // The incoming owner must be allowed to hold projects
+    #[account(
+        seeds = [b"user", new_owner.key().as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.is_active @ ErrorCode::UserNotActive,
+        constraint = (user_account.permissions & permissions::REGISTER_PROJECT) != 0 @ ErrorCode::InsufficientPermissions
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeSectorData<'info> {
+    #[account(
+        seeds = [b"project", project.project_id.as_bytes()],
+        bump = project.bump,
+        has_one = owner
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + ProjectSectorData::LEN,
+        seeds = [b"sector_data", project.key().as_ref(), project.generation.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub sector_data: Account<'info, ProjectSectorData>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateSectorData<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.project_id.as_bytes()],
+        bump = project.bump,
+        has_one = owner
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [b"sector_data", project.key().as_ref(), project.generation.to_le_bytes().as_ref()],
+        bump = sector_data.bump,
+        has_one = project
+    )]
+    pub sector_data: Account<'info, ProjectSectorData>,
+
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeVerification<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.project_id.as_bytes()],
+        bump = project.bump,
+        has_one = owner
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [b"registry_config"],
+        bump = registry_config.bump
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: The verifier address attempting to get paid
+    #[account(
+        constraint = verifier.key() != owner.key() @ ErrorCode::VerifierIsProjectOwner
+    )]
+    pub verifier: UncheckedAccount<'info>,
+
+    // Required whenever the registry or the project sets a minimum verifier stake
+    #[account(
+        seeds = [b"verifier_stake", verifier.key().as_ref()],
+        bump = verifier_stake.bump
+    )]
+    pub verifier_stake: Option<Account<'info, VerifierStake>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Account validation for verify_project instruction
+#[derive(Accounts)]
+pub struct VerifyProject<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.project_id.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump,
+        // has_one = admin // Removed generic admin check to allow validators
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    #[account(
+        seeds = [b"registry_config"],
+        bump = registry_config.bump
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    #[account(
+        mut,
+        constraint = admin.key() != project.owner @ ErrorCode::VerifierIsProjectOwner
+    )]
+    pub admin: Signer<'info>, // This is the verifier/validator
+
+    #[account(
+        seeds = [b"user", admin.key().as_ref()],
+        bump = admin_account.bump,
+        constraint = admin_account.is_active @ ErrorCode::UserNotActive,
+        constraint = (admin_account.permissions & permissions::VERIFY_PROJECT) != 0 @ ErrorCode::InsufficientPermissions
+    )]
+    pub admin_account: Account<'info, UserAccount>,
+
+    #[account(
+        seeds = [b"verifier", admin.key().as_ref()],
+        bump
+    )]
+    pub verifier_node: Account<'info, VerificationNode>,
+
+    // Required whenever the registry or the project sets a minimum verifier stake
+    #[account(
+        seeds = [b"verifier_stake", admin.key().as_ref()],
+        bump = verifier_stake.bump
+    )]
+    pub verifier_stake: Option<Account<'info, VerifierStake>>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + VerifierOwnerAudit::LEN,
+        seeds = [b"verifier_audit", admin.key().as_ref(), project.owner.as_ref()],
+        bump
+    )]
+    pub verifier_audit: Account<'info, VerifierOwnerAudit>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + VerificationRecord::LEN,
+        seeds = [b"verification_record", project.key().as_ref(), project.generation.to_le_bytes().as_ref(), project.verification_records.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub verification_record: Account<'info, VerificationRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct OpenDispute<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.project_id.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        init,
+        payer = challenger,
+        space = 8 + VerificationDispute::LEN,
+        seeds = [b"dispute", project.key().as_ref(), project.generation.to_le_bytes().as_ref(), project.disputes.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub dispute: Account<'info, VerificationDispute>,
+
+    #[account(
+        seeds = [b"registry_config"],
+        bump = registry_config.bump
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+
+    // Any registered user may challenge
+    #[account(
+        seeds = [b"user", challenger.key().as_ref()],
+        bump = challenger_account.bump,
+        constraint = challenger_account.is_active @ ErrorCode::UserNotActive
+    )]
+    pub challenger_account: Account<'info, UserAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExpireVerification<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.project_id.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    // Permissionless: anyone may crank an overdue project
+    pub cranker: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReleaseVerificationFee<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.project_id.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    /// CHECK: Payee recorded on the project at verification
+    #[account(
+        mut,
+        constraint = project.verifier == Some(verifier.key()) @ ErrorCode::UnauthorizedVerifier
+    )]
+    pub verifier: UncheckedAccount<'info>,
+
+    // Required when the escrow is held in a stablecoin
+    #[account(
+        mut,
+        seeds = [b"escrow_vault", project.key().as_ref()],
+        bump
+    )]
+    pub escrow_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub escrow_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = recipient_token_account.owner == verifier.key() @ ErrorCode::MissingEscrowTokenAccounts
+    )]
+    pub recipient_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimVerificationFee<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.project_id.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [b"verification_round", project.key().as_ref(), project.generation.to_le_bytes().as_ref(), verification_round.round.to_le_bytes().as_ref()],
+        bump = verification_round.bump,
+        has_one = project
+    )]
+    pub verification_round: Account<'info, VerificationRound>,
+
+    #[account(mut)]
+    pub verifier_authority: Signer<'info>,
+
+    // Required when the escrow is held in a stablecoin
+    #[account(
+        mut,
+        seeds = [b"escrow_vault", project.key().as_ref()],
+        bump
+    )]
+    pub escrow_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub escrow_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = recipient_token_account.owner == verifier_authority.key() @ ErrorCode::MissingEscrowTokenAccounts
+    )]
+    pub recipient_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimVerificationEscrow<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.project_id.as_bytes()],
+        bump = project.bump,
+        has_one = owner
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    // Latest verification round; required once any round has been opened
+    #[account(
+        seeds = [b"verification_round", project.key().as_ref(), project.generation.to_le_bytes().as_ref(), project.verification_rounds.saturating_sub(1).to_le_bytes().as_ref()],
+        bump = verification_round.bump,
+        has_one = project
+    )]
+    pub verification_round: Option<Account<'info, VerificationRound>>,
+
+    // Required when the escrow is held in a stablecoin
+    #[account(
+        mut,
+        seeds = [b"escrow_vault", project.key().as_ref()],
+        bump
+    )]
+    pub escrow_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub escrow_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = recipient_token_account.owner == owner.key() @ ErrorCode::MissingEscrowTokenAccounts
+    )]
+    pub recipient_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeStableVerification<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.project_id.as_bytes()],
+        bump = project.bump,
+        has_one = owner
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [b"registry_config"],
+        bump = registry_config.bump,
+        constraint = registry_config.stable_escrow_mint == Some(escrow_mint.key()) @ ErrorCode::InvalidEscrowMint
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: The verifier address attempting to get paid
+    #[account(
+        constraint = verifier.key() != owner.key() @ ErrorCode::VerifierIsProjectOwner
+    )]
+    pub verifier: UncheckedAccount<'info>,
+
+    // Required whenever the registry or the project sets a minimum verifier stake
+    #[account(
+        seeds = [b"verifier_stake", verifier.key().as_ref()],
+        bump = verifier_stake.bump
+    )]
+    pub verifier_stake: Option<Account<'info, VerifierStake>>,
+
+    pub escrow_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = escrow_mint,
+        token::authority = owner,
+        token::token_program = token_program
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [b"escrow_vault", project.key().as_ref()],
+        bump,
+        token::mint = escrow_mint,
+        token::authority = project,
+        token::token_program = token_program
+    )]
+    pub escrow_vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+// Account validation for reject_project instruction
+#[derive(Accounts)]
+pub struct RejectProject<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.project_id.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    #[account(
+        seeds = [b"registry_config"],
+        bump = registry_config.bump
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    #[account(
+        mut,
+        constraint = admin.key() != project.owner @ ErrorCode::VerifierIsProjectOwner
+    )]
+    pub admin: Signer<'info>, // This is the verifier/validator
+
+    #[account(
+        seeds = [b"user", admin.key().as_ref()],
+        bump = admin_account.bump,
+        constraint = admin_account.is_active @ ErrorCode::UserNotActive,
+        constraint = (admin_account.permissions & permissions::VERIFY_PROJECT) != 0 @ ErrorCode::InsufficientPermissions
+    )]
+    pub admin_account: Account<'info, UserAccount>,
+
+    #[account(
+        seeds = [b"verifier", admin.key().as_ref()],
+        bump
+    )]
+    pub verifier_node: Account<'info, VerificationNode>,
+
+    // Required whenever the registry or the project sets a minimum verifier stake
+    #[account(
+        seeds = [b"verifier_stake", admin.key().as_ref()],
+        bump = verifier_stake.bump
+    )]
+    pub verifier_stake: Option<Account<'info, VerifierStake>>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + VerifierOwnerAudit::LEN,
+        seeds = [b"verifier_audit", admin.key().as_ref(), project.owner.as_ref()],
+        bump
+    )]
+    pub verifier_audit: Account<'info, VerifierOwnerAudit>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + VerificationRecord::LEN,
+        seeds = [b"verification_record", project.key().as_ref(), project.generation.to_le_bytes().as_ref(), project.verification_records.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub verification_record: Account<'info, VerificationRecord>,
+
+    // Required when the escrow is held in a stablecoin
+    #[account(
+        mut,
+        seeds = [b"escrow_vault", project.key().as_ref()],
+        bump
+    )]
+    pub escrow_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub escrow_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = recipient_token_account.owner == admin.key() @ ErrorCode::MissingEscrowTokenAccounts
+    )]
+    pub recipient_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Account validation for mint_verified_credits instruction
+#[derive(Accounts)]
+#[instruction(vintage_year: u16, methodology: String, export_authorized: bool)]
+pub struct CreateCreditMint<'info> {
+    #[account(
+        seeds = [b"project", project.project_id.as_bytes()],
+        bump = project.bump,
+        has_one = owner
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    #[account(
+        seeds = [b"registry_config"],
+        bump = registry_config.bump
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    /// CHECK: Manual initialization (domestic-only mints add DefaultAccountState)
+    #[account(
+        mut,
+        seeds = [
+            b"credit_mint",
+            project.key().as_ref(),
+            project.generation.to_le_bytes().as_ref(),
+            vintage_year.to_le_bytes().as_ref(),
+            &[export_authorized as u8]
+        ],
+        bump
+    )]
+    pub credit_mint: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + CreditMintInfo::LEN,
+        seeds = [b"credit_mint_info", credit_mint.key().as_ref()],
+        bump
+    )]
+    pub credit_mint_info: Account<'info, CreditMintInfo>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(address = anchor_spl::token_2022::ID)]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateCreditMintMetadata<'info> {
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump,
+        has_one = admin @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    #[account(
+        seeds = [b"credit_mint_info", credit_mint.key().as_ref()],
+        bump = credit_mint_info.bump
+    )]
+    pub credit_mint_info: Account<'info, CreditMintInfo>,
+
+    #[account(mut)]
+    pub credit_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(address = anchor_spl::token_2022::ID)]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ThawCreditAccount<'info> {
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    #[account(
+        seeds = [b"credit_mint_info", credit_mint.key().as_ref()],
+        bump = credit_mint_info.bump
+    )]
+    pub credit_mint_info: Account<'info, CreditMintInfo>,
+
+    pub credit_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = credit_mint,
+        token::token_program = token_program
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    // Host-country residence of the token account's owner
+    #[account(
+        seeds = [b"jurisdiction", token_account.owner.as_ref()],
+        bump = jurisdiction.bump
+    )]
+    pub jurisdiction: Option<Account<'info, ParticipantJurisdiction>>,
+
+    // Permissionless: anyone may thaw an attested resident's account
+    pub cranker: Signer<'info>,
+
+    #[account(address = anchor_spl::token_2022::ID)]
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct SetProjectBufferRate<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.project_id.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump,
+        has_one = admin @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelBufferCredits<'info> {
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump,
+        has_one = admin @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    #[account(
+        mut,
+        seeds = [b"credit_mint_info", credit_mint.key().as_ref()],
+        bump = credit_mint_info.bump
+    )]
+    pub credit_mint_info: Account<'info, CreditMintInfo>,
+
+    #[account(
+        mut,
+        seeds = [b"issuance_record", credit_mint.key().as_ref(), issuance_record.index.to_le_bytes().as_ref()],
+        bump = issuance_record.bump,
+        has_one = credit_mint
+    )]
+    pub issuance_record: Account<'info, IssuanceRecord>,
+
+    #[account(mut)]
+    pub credit_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"buffer_vault", credit_mint.key().as_ref()],
+        bump
+    )]
+    pub buffer_vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub admin: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ReleaseBufferCredits<'info> {
+    #[account(
+        seeds = [b"project", project.project_id.as_bytes()],
+        bump = project.bump,
+        has_one = owner
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    #[account(
+        mut,
+        seeds = [b"credit_mint_info", credit_mint.key().as_ref()],
+        bump = credit_mint_info.bump,
+        constraint = credit_mint_info.project == project.key() @ ErrorCode::CreditMintMismatch
+    )]
+    pub credit_mint_info: Account<'info, CreditMintInfo>,
+
+    #[account(
+        mut,
+        seeds = [b"issuance_record", credit_mint.key().as_ref(), issuance_record.index.to_le_bytes().as_ref()],
+        bump = issuance_record.bump,
+        has_one = credit_mint
+    )]
+    pub issuance_record: Account<'info, IssuanceRecord>,
+
+    pub credit_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"buffer_vault", credit_mint.key().as_ref()],
+        bump
+    )]
+    pub buffer_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = credit_mint,
+        token::authority = owner
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    // Required when releasing domestic-only credits
+    #[account(
+        seeds = [b"jurisdiction", owner.key().as_ref()],
+        bump = owner_jurisdiction.bump
+    )]
+    pub owner_jurisdiction: Option<Account<'info, ParticipantJurisdiction>>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct MintVerifiedCredits<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.project_id.as_bytes()],
+        bump = project.bump,
+        has_one = owner
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [b"issuance_batch", project.key().as_ref(), project.generation.to_le_bytes().as_ref(), issuance_batch.index.to_le_bytes().as_ref()],
+        bump = issuance_batch.bump
+    )]
+    pub issuance_batch: Account<'info, IssuanceBatch>,
+
+    #[account(
+        mut,
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    #[account(
+        seeds = [b"registry_config"],
+        bump = registry_config.bump
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    // Either the LoA-backed or the domestic-only mint of the batch's vintage
+    #[account(mut)]
+    pub carbon_token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"credit_mint_info", carbon_token_mint.key().as_ref()],
+        bump = credit_mint_info.bump,
+        constraint = credit_mint_info.project == project.key() @ ErrorCode::CreditMintMismatch,
+        constraint = credit_mint_info.vintage_year == issuance_batch.vintage_year @ ErrorCode::CreditMintMismatch
+    )]
+    pub credit_mint_info: Account<'info, CreditMintInfo>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + IssuanceRecord::LEN,
+        seeds = [b"issuance_record", carbon_token_mint.key().as_ref(), credit_mint_info.issuances.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub issuance_record: Account<'info, IssuanceRecord>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        token::mint = carbon_token_mint,
+        token::authority = registry,
+        token::token_program = token_program,
+        seeds = [b"buffer_vault", carbon_token_mint.key().as_ref()],
+        bump
+    )]
+    pub buffer_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = carbon_token_mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    // Required when minting domestic-only credits
+    #[account(
+        seeds = [b"jurisdiction", recipient.key().as_ref()],
+        bump = recipient_jurisdiction.bump
+    )]
+    pub recipient_jurisdiction: Option<Account<'info, ParticipantJurisdiction>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub recipient: SystemAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BatchMintCredits<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.project_id.as_bytes()],
+        bump = project.bump,
+        has_one = owner
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [b"issuance_batch", project.key().as_ref(), project.generation.to_le_bytes().as_ref(), issuance_batch.index.to_le_bytes().as_ref()],
+        bump = issuance_batch.bump
+    )]
+    pub issuance_batch: Account<'info, IssuanceBatch>,
+
+    #[account(
+        mut,
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    #[account(
+        seeds = [b"registry_config"],
+        bump = registry_config.bump
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    // Either the LoA-backed or the domestic-only mint of the batch's vintage
+    #[account(mut)]
+    pub carbon_token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"credit_mint_info", carbon_token_mint.key().as_ref()],
+        bump = credit_mint_info.bump,
+        constraint = credit_mint_info.project == project.key() @ ErrorCode::CreditMintMismatch,
+        constraint = credit_mint_info.vintage_year == issuance_batch.vintage_year @ ErrorCode::CreditMintMismatch
+    )]
+    pub credit_mint_info: Account<'info, CreditMintInfo>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + IssuanceRecord::LEN,
+        seeds = [b"issuance_record", carbon_token_mint.key().as_ref(), credit_mint_info.issuances.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub issuance_record: Account<'info, IssuanceRecord>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        token::mint = carbon_token_mint,
+        token::authority = registry,
+        token::token_program = token_program,
+        seeds = [b"buffer_vault", carbon_token_mint.key().as_ref()],
+        bump
+    )]
+    pub buffer_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+// Accounts for the transfer_credits instruction
+#[derive(Accounts)]
+pub struct TransferCredits<'info> {
+    #[account(mut)]
+    /// CHECK: This is the source token account
+    pub from_account: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program
+    )]
+    pub to_account: InterfaceAccount<'info, TokenAccount>,
+    
+    pub from_authority: Signer<'info>,
+    pub mint: InterfaceAccount<'info, Mint>, // Required for transfer_checked
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    #[account(
+        seeds = [b"credit_mint_info", mint.key().as_ref()],
+        bump = credit_mint_info.bump
+    )]
+    pub credit_mint_info: Account<'info, CreditMintInfo>,
+
+    // Required when transferring domestic-only credits
+    #[account(
+        seeds = [b"jurisdiction", to_account.owner.as_ref()],
+        bump = recipient_jurisdiction.bump
+    )]
+    pub recipient_jurisdiction: Option<Account<'info, ParticipantJurisdiction>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64, retirement_id: String)]
+pub struct RetireCredits<'info> {
+    #[account(mut)]
+    pub credit_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"credit_mint_info", credit_mint.key().as_ref()],
+        bump = credit_mint_info.bump
+    )]
+    pub credit_mint_info: Account<'info, CreditMintInfo>,
+
+    /// Issuance whose serials this retirement cancels
+    #[account(
+        mut,
+        seeds = [b"issuance_record", credit_mint.key().as_ref(), issuance_record.index.to_le_bytes().as_ref()],
+        bump = issuance_record.bump,
+        has_one = credit_mint
+    )]
+    pub issuance_record: Account<'info, IssuanceRecord>,
+
+    #[account(
+        mut,
+        token::mint = credit_mint,
+        token::authority = owner
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: Verified by seeds, initialized in instruction
+    #[account(
+        mut,
+        seeds = [b"retirement", owner.key().as_ref(), retirement_id.as_bytes()],
+        bump
+    )]
+    pub certificate_mint: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + RetirementRecord::LEN,
+        seeds = [b"retirement_record", certificate_mint.key().as_ref()],
+        bump
+    )]
+    pub retirement_record: Account<'info, RetirementRecord>,
+
+    /// CHECK: Initialized in instruction
+    #[account(mut)]
+    pub certificate_token_account: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_2022_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+// Accounts for trade_credits (placeholder)
+#[derive(Accounts)]
+pub struct TradeCredits<'info> {
+    pub authority: Signer<'info>,
+}
+
+// Enhanced account validation contexts for blue carbon features
+
+
+#[derive(Accounts)]
+#[instruction(verifier_data: VerifierData)]
+pub struct RegisterVerifier<'info> {
+    #[account(
+        init,
+        payer = verifier_authority,
+        space = 8 + 1000,
+        seeds = [b"verifier", verifier_authority.key().as_ref()],
+        bump
+    )]
+    pub verifier: Account<'info, VerificationNode>,
+
+    #[account(
+        init,
+        payer = verifier_authority,
+        space = 8 + VerifierStake::LEN,
+        seeds = [b"verifier_stake", verifier_authority.key().as_ref()],
+        bump
+    )]
+    pub verifier_stake: Account<'info, VerifierStake>,
+
+    #[account(mut)]
+    pub verifier_authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct OpenIssuanceBatch<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.project_id.as_bytes()],
+        bump = project.bump,
+        has_one = owner
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + IssuanceBatch::LEN,
+        seeds = [b"issuance_batch", project.key().as_ref(), project.generation.to_le_bytes().as_ref(), project.issuance_batches.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub issuance_batch: Account<'info, IssuanceBatch>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyIssuanceBatch<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.project_id.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [b"issuance_batch", project.key().as_ref(), project.generation.to_le_bytes().as_ref(), issuance_batch.index.to_le_bytes().as_ref()],
+        bump = issuance_batch.bump
+    )]
+    pub issuance_batch: Account<'info, IssuanceBatch>,
+
+    #[account(
+        seeds = [b"registry_config"],
+        bump = registry_config.bump
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    #[account(
+        seeds = [b"user", verifier_authority.key().as_ref()],
+        bump = verifier_account.bump,
+        constraint = verifier_account.is_active @ ErrorCode::UserNotActive,
+        constraint = (verifier_account.permissions & permissions::VERIFY_PROJECT) != 0 @ ErrorCode::InsufficientPermissions
+    )]
+    pub verifier_account: Account<'info, UserAccount>,
+
+    #[account(
+        seeds = [b"verifier", verifier_authority.key().as_ref()],
+        bump
+    )]
+    pub verifier_node: Account<'info, VerificationNode>,
+
+    // Required whenever the registry or the project sets a minimum verifier stake
+    #[account(
+        seeds = [b"verifier_stake", verifier_authority.key().as_ref()],
+        bump = verifier_stake.bump
+    )]
+    pub verifier_stake: Option<Account<'info, VerifierStake>>,
+
+    #[account(
+        init_if_needed,
+        payer = verifier_authority,
+        space = 8 + VerifierOwnerAudit::LEN,
+        seeds = [b"verifier_audit", verifier_authority.key().as_ref(), project.owner.as_ref()],
+        bump
+    )]
+    pub verifier_audit: Account<'info, VerifierOwnerAudit>,
+
+    #[account(
+        init,
+        payer = verifier_authority,
+        space = 8 + VerificationRecord::LEN,
+        seeds = [b"verification_record", project.key().as_ref(), project.generation.to_le_bytes().as_ref(), project.verification_records.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub verification_record: Account<'info, VerificationRecord>,
+
+    #[account(
+        mut,
+        constraint = verifier_authority.key() != project.owner @ ErrorCode::VerifierIsProjectOwner
+    )]
+    pub verifier_authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PostAuditJob<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.project_id.as_bytes()],
+        bump = project.bump,
+        has_one = owner
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + AuditJob::LEN,
+        seeds = [b"audit_job", project.key().as_ref(), project.generation.to_le_bytes().as_ref(), project.audit_jobs.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub audit_job: Account<'info, AuditJob>,
+
+    #[account(
+        seeds = [b"registry_config"],
+        bump = registry_config.bump
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitAuditBid<'info> {
+    #[account(
+        seeds = [b"project", project.project_id.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [b"audit_job", project.key().as_ref(), project.generation.to_le_bytes().as_ref(), audit_job.index.to_le_bytes().as_ref()],
+        bump = audit_job.bump,
+        has_one = project
+    )]
+    pub audit_job: Account<'info, AuditJob>,
+
+    #[account(
+        init,
+        payer = verifier_authority,
+        space = 8 + AuditBid::LEN,
+        seeds = [b"audit_bid", audit_job.key().as_ref(), verifier_authority.key().as_ref()],
+        bump
+    )]
+    pub audit_bid: Account<'info, AuditBid>,
+
+    #[account(
+        seeds = [b"verifier", verifier_authority.key().as_ref()],
+        bump
+    )]
+    pub verifier: Account<'info, VerificationNode>,
+
+    #[account(
+        seeds = [b"verifier_stake", verifier_authority.key().as_ref()],
+        bump = verifier_stake.bump,
+        constraint = verifier_stake.staked >= registry_config.verifier_stake_for(&project) @ ErrorCode::InsufficientVerifierStake
+    )]
+    pub verifier_stake: Account<'info, VerifierStake>,
+
+    #[account(
+        seeds = [b"registry_config"],
+        bump = registry_config.bump
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    #[account(
+        mut,
+        constraint = verifier_authority.key() != project.owner @ ErrorCode::VerifierIsProjectOwner
+    )]
+    pub verifier_authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuditBid<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.project_id.as_bytes()],
+        bump = project.bump,
+        has_one = owner
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [b"audit_job", project.key().as_ref(), project.generation.to_le_bytes().as_ref(), audit_job.index.to_le_bytes().as_ref()],
+        bump = audit_job.bump,
+        has_one = project
+    )]
+    pub audit_job: Account<'info, AuditJob>,
+
+    #[account(
+        seeds = [b"audit_bid", audit_job.key().as_ref(), audit_bid.verifier.as_ref()],
+        bump = audit_bid.bump,
+        has_one = audit_job,
+        constraint = audit_bid.verifier != project.owner @ ErrorCode::VerifierIsProjectOwner
+    )]
+    pub audit_bid: Account<'info, AuditBid>,
+
+    // The bidder's eligibility is re-checked at award time
+    #[account(
+        seeds = [b"verifier", audit_bid.verifier.as_ref()],
+        bump
+    )]
+    pub verifier: Account<'info, VerificationNode>,
+
+    #[account(
+        seeds = [b"verifier_stake", audit_bid.verifier.as_ref()],
+        bump = verifier_stake.bump,
+        constraint = verifier_stake.staked >= registry_config.verifier_stake_for(&project) @ ErrorCode::InsufficientVerifierStake
+    )]
+    pub verifier_stake: Account<'info, VerifierStake>,
+
+    #[account(
+        seeds = [b"registry_config"],
+        bump = registry_config.bump
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct InitializeVerification<'info> {
+pub struct CancelAuditJob<'info> {
     #[account(
-        mut,
-        seeds = [b"project", project.owner.as_ref(), project.project_id.as_bytes()],
+        seeds = [b"project", project.project_id.as_bytes()],
         bump = project.bump,
         has_one = owner
     )]
     pub project: Account<'info, Project>,
 
-    #[account(mut)]
-    pub owner: Signer<'info>,
-
-    /// CHECK: The verifier address attempting to get paid
-    pub verifier: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"audit_job", project.key().as_ref(), project.generation.to_le_bytes().as_ref(), audit_job.index.to_le_bytes().as_ref()],
+        bump = audit_job.bump,
+        has_one = project
+    )]
+    pub audit_job: Account<'info, AuditJob>,
 
-    pub system_program: Program<'info, System>,
+    pub owner: Signer<'info>,
 }
 
-// Account validation for verify_project instruction
 #[derive(Accounts)]
-pub struct VerifyProject<'info> {
+pub struct WithdrawAuditBid<'info> {
+    #[account(mut)]
+    pub audit_job: Account<'info, AuditJob>,
+
     #[account(
         mut,
-        seeds = [b"project", project.owner.as_ref(), project.project_id.as_bytes()],
-        bump = project.bump
+        close = verifier_authority,
+        seeds = [b"audit_bid", audit_job.key().as_ref(), verifier_authority.key().as_ref()],
+        bump = audit_bid.bump,
+        has_one = audit_job
     )]
-    pub project: Account<'info, Project>,
+    pub audit_bid: Account<'info, AuditBid>,
+
+    #[account(mut)]
+    pub verifier_authority: Signer<'info>,
+}
 
+#[derive(Accounts)]
+pub struct ManageVerifierStatus<'info> {
     #[account(
-        seeds = [b"registry_v3"],
-        bump = registry.bump,
-        // has_one = admin // Removed generic admin check to allow validators
+        mut,
+        seeds = [b"verifier", verifier.verifier_pubkey.as_ref()],
+        bump
     )]
-    pub registry: Account<'info, GlobalRegistry>,
+    pub verifier: Account<'info, VerificationNode>,
 
-    pub admin: Signer<'info>, // This is the verifier/validator
+    pub admin: Signer<'info>,
 
     #[account(
         seeds = [b"user", admin.key().as_ref()],
         bump = admin_account.bump,
         constraint = admin_account.is_active @ ErrorCode::UserNotActive,
-        constraint = (admin_account.permissions & permissions::VERIFY_PROJECT) != 0 @ ErrorCode::InsufficientPermissions
+        constraint = admin_account.role == UserRole::Admin ||
+                     admin_account.role == UserRole::SuperAdmin @ ErrorCode::UnauthorizedAdmin
     )]
     pub admin_account: Account<'info, UserAccount>,
 }
 
-// Account validation for reject_project instruction
 #[derive(Accounts)]
-pub struct RejectProject<'info> {
+pub struct UpdateVerifierCredentials<'info> {
     #[account(
         mut,
-        seeds = [b"project", project.owner.as_ref(), project.project_id.as_bytes()],
-        bump = project.bump
-    )]
-    pub project: Account<'info, Project>,
-
-    #[account(
-        seeds = [b"registry_v3"],
-        bump = registry.bump,
+        seeds = [b"verifier", authority.key().as_ref()],
+        bump,
+        constraint = verifier.verifier_pubkey == authority.key() @ ErrorCode::UnauthorizedVerifier
     )]
-    pub registry: Account<'info, GlobalRegistry>,
-
-    pub admin: Signer<'info>, // This is the verifier/validator
+    pub verifier: Account<'info, VerificationNode>,
 
-    #[account(
-        seeds = [b"user", admin.key().as_ref()],
-        bump = admin_account.bump,
-        constraint = admin_account.is_active @ ErrorCode::UserNotActive,
-        constraint = (admin_account.permissions & permissions::VERIFY_PROJECT) != 0 @ ErrorCode::InsufficientPermissions
-    )]
-    pub admin_account: Account<'info, UserAccount>,
+    pub authority: Signer<'info>,
 }
 
-// Account validation for mint_verified_credits instruction
 #[derive(Accounts)]
-pub struct MintVerifiedCredits<'info> {
+pub struct DeregisterVerifier<'info> {
     #[account(
         mut,
-        seeds = [b"project", project.owner.as_ref(), project.project_id.as_bytes()],
-        bump = project.bump,
-        has_one = owner
+        close = authority,
+        seeds = [b"verifier", authority.key().as_ref()],
+        bump,
+        constraint = verifier.verifier_pubkey == authority.key() @ ErrorCode::UnauthorizedVerifier
     )]
-    pub project: Account<'info, Project>,
+    pub verifier: Account<'info, VerificationNode>,
 
     #[account(
         mut,
-        seeds = [b"registry_v3"],
-        bump = registry.bump
+        close = authority,
+        seeds = [b"verifier_stake", authority.key().as_ref()],
+        bump = verifier_stake.bump,
+        has_one = authority,
+        constraint = verifier_stake.staked == 0 && verifier_stake.unbonding == 0 @ ErrorCode::VerifierStillStaked
     )]
-    pub registry: Account<'info, GlobalRegistry>,
+    pub verifier_stake: Account<'info, VerifierStake>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
 
+#[derive(Accounts)]
+pub struct ManageVerifierStake<'info> {
     #[account(
         mut,
-        seeds = [b"carbon_token_mint_v3"],
-        bump
+        seeds = [b"verifier_stake", authority.key().as_ref()],
+        bump = verifier_stake.bump,
+        has_one = authority
     )]
-    pub carbon_token_mint: InterfaceAccount<'info, Mint>,
+    pub verifier_stake: Account<'info, VerifierStake>,
 
     #[account(
-        mut,
-        associated_token::mint = carbon_token_mint,
-        associated_token::authority = recipient,
-        associated_token::token_program = token_program
+        seeds = [b"registry_config"],
+        bump = registry_config.bump
     )]
-    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub registry_config: Account<'info, RegistryConfig>,
 
-    pub owner: Signer<'info>,
-    pub recipient: SystemAccount<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
 
-    pub token_program: Interface<'info, TokenInterface>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct BatchMintCredits<'info> {
+pub struct SetMinVerifierStake<'info> {
     #[account(
         mut,
-        seeds = [b"project", project.owner.as_ref(), project.project_id.as_bytes()],
+        seeds = [b"project", project.project_id.as_bytes()],
         bump = project.bump,
         has_one = owner
     )]
     pub project: Account<'info, Project>,
 
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct OpenVerificationRound<'info> {
     #[account(
         mut,
+        seeds = [b"project", project.project_id.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    // Rounds and their panels are set by the registry, never by the project owner
+    #[account(
         seeds = [b"registry_v3"],
-        bump = registry.bump
+        bump = registry.bump,
+        has_one = admin @ ErrorCode::UnauthorizedAdmin
     )]
     pub registry: Account<'info, GlobalRegistry>,
 
     #[account(
-        mut,
-        seeds = [b"carbon_token_mint_v3"],
+        init,
+        payer = admin,
+        space = 8 + VerificationRound::LEN,
+        seeds = [b"verification_round", project.key().as_ref(), project.generation.to_le_bytes().as_ref(), project.verification_rounds.to_le_bytes().as_ref()],
         bump
     )]
-    pub carbon_token_mint: InterfaceAccount<'info, Mint>,
+    pub verification_round: Account<'info, VerificationRound>,
 
-    pub owner: Signer<'info>,
+    #[account(
+        seeds = [b"registry_config"],
+        bump = registry_config.bump
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
 
-    pub token_program: Interface<'info, TokenInterface>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
-// DEPRECATED: Legacy mint_credits account structure
 #[derive(Accounts)]
-pub struct MintCredits<'info> {
-    #[account(mut)]
-    /// CHECK: This is the mint account for the token
-    pub mint: AccountInfo<'info>,
-
+pub struct FinalizeVerificationRound<'info> {
     #[account(
         mut,
-        has_one = owner,
-        seeds = [b"project", project.owner.as_ref(), project.project_id.as_bytes()],
+        seeds = [b"project", project.project_id.as_bytes()],
         bump = project.bump
     )]
     pub project: Account<'info, Project>,
 
-    #[account(mut)]
-    pub owner: Signer<'info>,
-
-    #[account(mut)]
-    /// CHECK: This is the recipient token account
-    pub recipient_token_account: AccountInfo<'info>,
-
-    /// CHECK: The mint authority is a PDA, so we validate it with seeds.
     #[account(
-        seeds = [b"project", project.owner.as_ref(), project.project_id.as_bytes()],
-        bump = project.bump
+        mut,
+        seeds = [b"verification_round", project.key().as_ref(), project.generation.to_le_bytes().as_ref(), verification_round.round.to_le_bytes().as_ref()],
+        bump = verification_round.bump,
+        has_one = project
     )]
-    pub mint_authority: AccountInfo<'info>,
-
-    pub token_program: Interface<'info, TokenInterface>,
-}
-
-// Accounts for the transfer_credits instruction
-#[derive(Accounts)]
-pub struct TransferCredits<'info> {
-    #[account(mut)]
-    /// CHECK: This is the source token account
-    pub from_account: AccountInfo<'info>,
-    
-    #[account(mut)]
-    /// CHECK: This is the destination token account
-    pub to_account: AccountInfo<'info>,
-    
-    pub from_authority: Signer<'info>,
-    pub mint: InterfaceAccount<'info, Mint>, // Required for transfer_checked
-    pub token_program: Interface<'info, TokenInterface>,
-}
-
-#[derive(Accounts)]
-#[instruction(amount: u64, retirement_id: String)]
-pub struct RetireCredits<'info> {
-    #[account(mut)]
-    pub credit_mint: InterfaceAccount<'info, Mint>,
+    pub verification_round: Account<'info, VerificationRound>,
 
     #[account(
-        mut,
-        token::mint = credit_mint,
-        token::authority = owner
+        seeds = [b"registry_config"],
+        bump = registry_config.bump
     )]
-    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
-
-    #[account(mut)]
-    pub owner: Signer<'info>,
+    pub registry_config: Account<'info, RegistryConfig>,
 
-    /// CHECK: Verified by seeds, initialized in instruction
     #[account(
-        mut,
-        seeds = [b"retirement", owner.key().as_ref(), retirement_id.as_bytes()],
+        init,
+        payer = authority,
+        space = 8 + VerificationRecord::LEN,
+        seeds = [b"verification_record", project.key().as_ref(), project.generation.to_le_bytes().as_ref(), project.verification_records.to_le_bytes().as_ref()],
         bump
     )]
-    pub certificate_mint: UncheckedAccount<'info>,
+    pub verification_record: Account<'info, VerificationRecord>,
 
-    /// CHECK: Initialized in instruction
     #[account(mut)]
-    pub certificate_token_account: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
 
-    pub token_program: Interface<'info, TokenInterface>,
-    pub token_2022_program: Interface<'info, TokenInterface>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
 }
 
-// Accounts for trade_credits (placeholder)
 #[derive(Accounts)]
-pub struct TradeCredits<'info> {
-    pub authority: Signer<'info>,
-}
+pub struct MultiPartyVerifyProject<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.project_id.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
 
-// Enhanced account validation contexts for blue carbon features
+    #[account(
+        seeds = [b"registry_config"],
+        bump = registry_config.bump
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
 
+    #[account(
+        mut,
+        seeds = [b"verification_round", project.key().as_ref(), project.generation.to_le_bytes().as_ref(), verification_round.round.to_le_bytes().as_ref()],
+        bump = verification_round.bump,
+        has_one = project
+    )]
+    pub verification_round: Account<'info, VerificationRound>,
 
-#[derive(Accounts)]
-#[instruction(verifier_data: VerifierData)]
-pub struct RegisterVerifier<'info> {
     #[account(
-        init,
-        payer = verifier_authority,
-        space = 8 + 1000,
+        mut,
         seeds = [b"verifier", verifier_authority.key().as_ref()],
         bump
     )]
     pub verifier: Account<'info, VerificationNode>,
 
-    #[account(mut)]
-    pub verifier_authority: Signer<'info>,
+    #[account(
+        seeds = [b"verifier_stake", verifier_authority.key().as_ref()],
+        bump = verifier_stake.bump,
+        constraint = verifier_stake.staked >= registry_config.verifier_stake_for(&project) @ ErrorCode::InsufficientVerifierStake
+    )]
+    pub verifier_stake: Account<'info, VerifierStake>,
 
-    pub system_program: Program<'info, System>,
-}
+    #[account(
+        seeds = [b"user", verifier_authority.key().as_ref()],
+        bump = verifier_account.bump,
+        constraint = verifier_account.is_active @ ErrorCode::UserNotActive,
+        constraint = (verifier_account.permissions & permissions::VERIFY_PROJECT) != 0 @ ErrorCode::InsufficientPermissions
+    )]
+    pub verifier_account: Account<'info, UserAccount>,
 
-#[derive(Accounts)]
-pub struct MultiPartyVerifyProject<'info> {
     #[account(
-        mut,
-        seeds = [b"project", project.owner.as_ref(), project.project_id.as_bytes()],
-        bump = project.bump
+        init_if_needed,
+        payer = verifier_authority,
+        space = 8 + VerifierOwnerAudit::LEN,
+        seeds = [b"verifier_audit", verifier_authority.key().as_ref(), project.owner.as_ref()],
+        bump
     )]
-    pub project: Account<'info, Project>,
+    pub verifier_audit: Account<'info, VerifierOwnerAudit>,
 
     #[account(
-        mut,
-        seeds = [b"verifier", verifier_authority.key().as_ref()],
+        init,
+        payer = verifier_authority,
+        space = 8 + VerificationRecord::LEN,
+        seeds = [b"verification_record", project.key().as_ref(), project.generation.to_le_bytes().as_ref(), project.verification_records.to_le_bytes().as_ref()],
         bump
     )]
-    pub verifier: Account<'info, VerificationNode>,
+    pub verification_record: Account<'info, VerificationRecord>,
 
+    #[account(
+        mut,
+        constraint = verifier_authority.key() != project.owner @ ErrorCode::VerifierIsProjectOwner
+    )]
     pub verifier_authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
@@ -361,11 +1779,17 @@ pub struct SubmitMonitoringData<'info> {
 
     #[account(
         mut,
-        seeds = [b"project", project.owner.as_ref(), project.project_id.as_bytes()],
+        seeds = [b"project", project.project_id.as_bytes()],
         bump = project.bump
     )]
     pub project: Account<'info, Project>,
 
+    #[account(
+        seeds = [b"registry_config"],
+        bump = registry_config.bump
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
     #[account(mut)]
     pub data_provider: Signer<'info>,
 
@@ -396,7 +1820,7 @@ pub struct CreateMarketplaceListing<'info> {
     pub listing_vault: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
-        seeds = [b"project", project.owner.as_ref(), project.project_id.as_bytes()],
+        seeds = [b"project", project.project_id.as_bytes()],
         bump = project.bump
     )]
     pub project: Account<'info, Project>,
@@ -408,6 +1832,21 @@ pub struct CreateMarketplaceListing<'info> {
     pub seller_credit_account: InterfaceAccount<'info, TokenAccount>,
 
     pub credit_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [b"credit_mint_info", credit_mint.key().as_ref()],
+        bump = credit_mint_info.bump,
+        constraint = credit_mint_info.project == project.key() @ ErrorCode::CreditMintMismatch
+    )]
+    pub credit_mint_info: Account<'info, CreditMintInfo>,
+
+    // Freeze authority; thaws the vault of a domestic-only listing
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
@@ -447,6 +1886,19 @@ pub struct BuyMarketplaceListing<'info> {
     pub credit_mint: InterfaceAccount<'info, Mint>,
     pub currency_mint: InterfaceAccount<'info, Mint>,
 
+    #[account(
+        seeds = [b"credit_mint_info", credit_mint.key().as_ref()],
+        bump = credit_mint_info.bump
+    )]
+    pub credit_mint_info: Account<'info, CreditMintInfo>,
+
+    // Required when buying domestic-only credits
+    #[account(
+        seeds = [b"jurisdiction", buyer_credit_account.owner.as_ref()],
+        bump = buyer_jurisdiction.bump
+    )]
+    pub buyer_jurisdiction: Option<Account<'info, ParticipantJurisdiction>>,
+
     pub token_program: Interface<'info, TokenInterface>,
 }
 
@@ -532,6 +1984,19 @@ pub struct InitializePool<'info> {
     pub credit_mint: InterfaceAccount<'info, Mint>,
     pub quote_mint: InterfaceAccount<'info, Mint>,
 
+    #[account(
+        seeds = [b"credit_mint_info", credit_mint.key().as_ref()],
+        bump = credit_mint_info.bump
+    )]
+    pub credit_mint_info: Account<'info, CreditMintInfo>,
+
+    // Freeze authority; thaws the credit vault of a domestic-only pool
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
     #[account(
         init,
         payer = authority,
@@ -601,6 +2066,19 @@ pub struct AddLiquidity<'info> {
     #[account(mut)]
     pub user_quote_account: InterfaceAccount<'info, TokenAccount>,
 
+    #[account(
+        seeds = [b"credit_mint_info", pool.credit_mint.as_ref()],
+        bump = credit_mint_info.bump
+    )]
+    pub credit_mint_info: Account<'info, CreditMintInfo>,
+
+    // Required when withdrawing domestic-only credits
+    #[account(
+        seeds = [b"jurisdiction", user_credit_account.owner.as_ref()],
+        bump = recipient_jurisdiction.bump
+    )]
+    pub recipient_jurisdiction: Option<Account<'info, ParticipantJurisdiction>>,
+
     #[account(
         mut,
         associated_token::mint = lp_mint,
@@ -656,6 +2134,19 @@ pub struct RemoveLiquidity<'info> {
     #[account(mut)]
     pub user_quote_account: InterfaceAccount<'info, TokenAccount>,
 
+    #[account(
+        seeds = [b"credit_mint_info", pool.credit_mint.as_ref()],
+        bump = credit_mint_info.bump
+    )]
+    pub credit_mint_info: Account<'info, CreditMintInfo>,
+
+    // Required when withdrawing domestic-only credits
+    #[account(
+        seeds = [b"jurisdiction", user_credit_account.owner.as_ref()],
+        bump = recipient_jurisdiction.bump
+    )]
+    pub recipient_jurisdiction: Option<Account<'info, ParticipantJurisdiction>>,
+
     #[account(
         mut,
         associated_token::mint = lp_mint,
@@ -702,6 +2193,19 @@ pub struct Swap<'info> {
     #[account(mut)]
     pub user_destination_account: InterfaceAccount<'info, TokenAccount>,
 
+    #[account(
+        seeds = [b"credit_mint_info", pool.credit_mint.as_ref()],
+        bump = credit_mint_info.bump
+    )]
+    pub credit_mint_info: Account<'info, CreditMintInfo>,
+
+    // Required when swapping into domestic-only credits
+    #[account(
+        seeds = [b"jurisdiction", user_destination_account.owner.as_ref()],
+        bump = recipient_jurisdiction.bump
+    )]
+    pub recipient_jurisdiction: Option<Account<'info, ParticipantJurisdiction>>,
+
     #[account(mut)]
     pub user: Signer<'info>,
 
@@ -0,0 +1,468 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{
+    self,
+    spl_pod::optional_keys::OptionalNonZeroPubkey,
+    spl_token_metadata_interface::state::{Field, TokenMetadata},
+    DefaultAccountStateInitialize, InitializeMint2, MetadataPointerInitialize, ThawAccount,
+    TokenMetadataInitialize, TokenMetadataUpdateField,
+};
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+    state::{Account as Token2022Account, AccountState, Mint as Token2022Mint},
+};
+use crate::models::*;
+use crate::instructions::{contexts::*, errors::ErrorCode};
+
+const CREDIT_SYMBOL: &str = "ACCORD";
+
+/// Key/value provenance fields stored in a credit mint's TokenMetadata
+fn credit_metadata_fields(
+    project: &Project,
+    vintage_year: u16,
+    methodology: &str,
+    export_authorized: bool,
+) -> Vec<(String, String)> {
+    vec![
+        ("project_id".to_string(), project.project_id.clone()),
+        ("sector".to_string(), format!("{:?}", project.project_sector)),
+        ("vintage".to_string(), vintage_year.to_string()),
+        ("methodology".to_string(), methodology.to_string()),
+        ("country_code".to_string(), project.location.country_code.clone()),
+        ("pdd_uri".to_string(), format!("ipfs://{}", project.ipfs_cid)),
+        ("compliance_status".to_string(), project.compliance.audit_status.clone()),
+        ("export_authorized".to_string(), export_authorized.to_string()),
+    ]
+}
+
+/// Token base units per tonne for a credit mint with `decimals`. Always taken
+/// from the mint itself so capacity checks match the tokens actually minted.
+pub(crate) fn credit_scale(decimals: u8) -> Result<u64> {
+    let scale = 10u64.checked_pow(decimals as u32).ok_or(ErrorCode::MathOverflow)?;
+    Ok(scale)
+}
+
+/// Whole tonnes in `amount` base units; serials are allocated one per tonne
+fn whole_tonnes(amount: u64, scale: u64) -> Result<u64> {
+    require!(amount > 0 && amount % scale == 0, ErrorCode::FractionalTonnes);
+    Ok(amount / scale)
+}
+
+/// Allocates the next contiguous issuance serials of `info` for `amount`
+/// base units and returns the inclusive range.
+pub(crate) fn allocate_serials(info: &mut CreditMintInfo, amount: u64, scale: u64) -> Result<(u64, u64)> {
+    let tonnes = whole_tonnes(amount, scale)?;
+    let start = info.serials_issued + 1;
+    info.serials_issued = info.serials_issued.checked_add(tonnes).ok_or(ErrorCode::MathOverflow)?;
+    Ok((start, info.serials_issued))
+}
+
+/// Retires the next `amount` base units of `record`'s serials and returns the
+/// inclusive range. Credits are fungible, so a retirement cancels the
+/// issuance's serials in order rather than the holder's own units. Buffer
+/// serials at the end of the range only become retirable once released.
+pub(crate) fn retire_serials(
+    info: &mut CreditMintInfo,
+    record: &mut IssuanceRecord,
+    amount: u64,
+    scale: u64,
+) -> Result<(u64, u64)> {
+    let tonnes = whole_tonnes(amount, scale)?;
+    let mut retirable = record.amount / scale;
+    if record.buffer_held == 0 {
+        retirable += (record.buffer_amount - record.buffer_cancelled) / scale;
+    }
+    let retired = record.serials_retired.checked_add(tonnes).ok_or(ErrorCode::MathOverflow)?;
+    require!(retired <= retirable, ErrorCode::ExceedsAvailableQuantity);
+    let start = record.serial_start + record.serials_retired;
+    record.serials_retired = retired;
+    info.serials_retired = info.serials_retired.checked_add(tonnes).ok_or(ErrorCode::MathOverflow)?;
+    Ok((start, start + tonnes - 1))
+}
+
+/// Cancels `amount` base units of `record`'s withheld buffer serials, taken
+/// down from the end of its range, and returns the inclusive range.
+pub(crate) fn cancel_buffer_serials(
+    info: &mut CreditMintInfo,
+    record: &mut IssuanceRecord,
+    amount: u64,
+    scale: u64,
+) -> Result<(u64, u64)> {
+    let tonnes = whole_tonnes(amount, scale)?;
+    require!(amount <= record.buffer_held, ErrorCode::InsufficientBufferCredits);
+    let end = record.serial_end - record.buffer_cancelled / scale;
+    record.buffer_cancelled += amount;
+    record.buffer_held -= amount;
+    info.serials_retired = info.serials_retired.checked_add(tonnes).ok_or(ErrorCode::MathOverflow)?;
+    Ok((end + 1 - tonnes, end))
+}
+
+/// Counts LoA-backed issuance against the project's authorized export limit.
+/// Domestic-only issuance is not limited.
+pub(crate) fn record_export_issuance(
+    project: &mut Project,
+    info: &CreditMintInfo,
+    issued: u64,
+    scale: u64,
+) -> Result<()> {
+    if !info.export_authorized {
+        return Ok(());
+    }
+    require!(project.compliance.loa_issued, ErrorCode::LoaNotIssued);
+    let limit = project
+        .compliance
+        .authorized_export_limit
+        .checked_mul(scale)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let exported = project.export_tokens_issued.checked_add(issued).ok_or(ErrorCode::MathOverflow)?;
+    require!(exported <= limit, ErrorCode::ExceedsExportLimit);
+    project.export_tokens_issued = exported;
+    Ok(())
+}
+
+/// Domestic-only credits may only be delivered to wallets attested as
+/// resident in the project's host country.
+pub(crate) fn check_credit_recipient(
+    info: &CreditMintInfo,
+    jurisdiction: Option<&Account<ParticipantJurisdiction>>,
+) -> Result<()> {
+    if info.export_authorized {
+        return Ok(());
+    }
+    let jurisdiction = jurisdiction.ok_or(ErrorCode::DomesticOnlyCredits)?;
+    require!(
+        jurisdiction.country_code == info.country_code,
+        ErrorCode::DomesticOnlyCredits
+    );
+    Ok(())
+}
+
+/// `check_credit_recipient` for a batch recipient whose token account and
+/// ParticipantJurisdiction are passed as unchecked remaining accounts.
+pub(crate) fn check_batch_recipient(
+    info: &CreditMintInfo,
+    token_account: &AccountInfo,
+    jurisdiction: &AccountInfo,
+) -> Result<()> {
+    let owner = {
+        let data = token_account.try_borrow_data()?;
+        StateWithExtensions::<Token2022Account>::unpack(&data)?.base.owner
+    };
+    let (expected, _) = Pubkey::find_program_address(&[b"jurisdiction", owner.as_ref()], &crate::ID);
+    require_keys_eq!(jurisdiction.key(), expected, ErrorCode::DomesticOnlyCredits);
+    require_keys_eq!(*jurisdiction.owner, crate::ID, ErrorCode::DomesticOnlyCredits);
+    let jurisdiction = ParticipantJurisdiction::try_deserialize(&mut &jurisdiction.try_borrow_data()?[..])?;
+    require!(
+        info.export_authorized || jurisdiction.country_code == info.country_code,
+        ErrorCode::DomesticOnlyCredits
+    );
+    Ok(())
+}
+
+/// Thaws a token account of a domestic-only credit mint, which Token-2022
+/// creates frozen (DefaultAccountState). Callers check the holder first.
+pub(crate) fn thaw_if_frozen<'info>(
+    account: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    registry: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    registry_bump: u8,
+) -> Result<()> {
+    let frozen = {
+        let data = account.try_borrow_data()?;
+        StateWithExtensions::<Token2022Account>::unpack(&data)?.base.state == AccountState::Frozen
+    };
+    if !frozen {
+        return Ok(());
+    }
+
+    let seeds = &[b"registry_v3".as_ref(), &[registry_bump]];
+    token_interface::thaw_account(CpiContext::new_with_signer(
+        token_program.clone(),
+        ThawAccount {
+            account: account.clone(),
+            mint: mint.clone(),
+            authority: registry.clone(),
+        },
+        &[&seeds[..]],
+    ))?;
+    msg!("🧊 Thawed {} for domestic-only credits", account.key());
+    Ok(())
+}
+
+/// Lets a resident of the host country hold domestic-only credits by thawing
+/// their token account. Needed before buying on the marketplace or DEX, or
+/// receiving a plain Token-2022 transfer. Permissionless.
+pub fn thaw_credit_account(ctx: Context<ThawCreditAccount>) -> Result<()> {
+    check_credit_recipient(&ctx.accounts.credit_mint_info, ctx.accounts.jurisdiction.as_ref())?;
+    thaw_if_frozen(
+        &ctx.accounts.token_account.to_account_info(),
+        &ctx.accounts.credit_mint.to_account_info(),
+        &ctx.accounts.registry.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.registry.bump,
+    )
+}
+
+/// Tops up `mint` so it stays rent exempt once its TokenMetadata entry grows
+/// from `current_tlv_len` bytes to the size of `metadata`.
+fn fund_metadata_space<'info>(
+    mint: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program_info: &AccountInfo<'info>,
+    current_tlv_len: usize,
+    metadata: &TokenMetadata,
+) -> Result<()> {
+    let new_len = mint.data_len() - current_tlv_len + metadata.tlv_size_of()?;
+    let top_up = Rent::get()?.minimum_balance(new_len).saturating_sub(mint.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program_info.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: mint.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    Ok(())
+}
+
+/// Creates the Token-2022 credit mint for one vintage and export class of a
+/// verified project. LoA-backed and domestic-only credits never share a mint.
+/// The registry PDA is mint, freeze and metadata authority; the mint carries
+/// its provenance in TokenMetadata and is recorded in a `CreditMintInfo`
+/// lookup so any holder can trace it back to its project.
+///
+/// Domestic-only mints also default new token accounts to frozen, so credits
+/// can only reach accounts the registry thawed for host-country residents.
+pub fn create_credit_mint(
+    ctx: Context<CreateCreditMint>,
+    vintage_year: u16,
+    methodology: String,
+    export_authorized: bool,
+) -> Result<()> {
+    let project = &ctx.accounts.project;
+
+    require!(
+        project.verification_status == VerificationStatus::Verified,
+        ErrorCode::ProjectNotVerified
+    );
+    require!(
+        !methodology.is_empty() && methodology.len() <= CreditMintInfo::MAX_METADATA_VALUE_LEN,
+        ErrorCode::InvalidMetadataField
+    );
+    require!(
+        project.location.country_code.len() <= ParticipantJurisdiction::MAX_COUNTRY_CODE_LEN,
+        ErrorCode::InvalidMetadataField
+    );
+    require!(
+        !export_authorized || project.compliance.loa_issued,
+        ErrorCode::LoaNotIssued
+    );
+
+    let mint_info = ctx.accounts.credit_mint.to_account_info();
+    let registry_info = ctx.accounts.registry.to_account_info();
+    let token_program_info = ctx.accounts.token_program.to_account_info();
+
+    // ========================================
+    // Create the mint with its extensions
+    // ========================================
+    let mut extensions = vec![ExtensionType::MetadataPointer];
+    if !export_authorized {
+        extensions.push(ExtensionType::DefaultAccountState);
+    }
+    let space = ExtensionType::try_calculate_account_len::<Token2022Mint>(&extensions)?;
+    let project_key = project.key();
+    let generation_bytes = project.generation.to_le_bytes();
+    let vintage_bytes = vintage_year.to_le_bytes();
+    let mint_seeds = &[
+        b"credit_mint".as_ref(),
+        project_key.as_ref(),
+        generation_bytes.as_ref(),
+        vintage_bytes.as_ref(),
+        &[export_authorized as u8],
+        &[ctx.bumps.credit_mint],
+    ];
+    system_program::create_account(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::CreateAccount {
+                from: ctx.accounts.owner.to_account_info(),
+                to: mint_info.clone(),
+            },
+            &[&mint_seeds[..]],
+        ),
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        token_program_info.key,
+    )?;
+
+    token_interface::metadata_pointer_initialize(
+        CpiContext::new(
+            token_program_info.clone(),
+            MetadataPointerInitialize {
+                token_program_id: token_program_info.clone(),
+                mint: mint_info.clone(),
+            },
+        ),
+        Some(registry_info.key()),
+        Some(mint_info.key()),
+    )?;
+    if !export_authorized {
+        token_interface::default_account_state_initialize(
+            CpiContext::new(
+                token_program_info.clone(),
+                DefaultAccountStateInitialize {
+                    token_program_id: token_program_info.clone(),
+                    mint: mint_info.clone(),
+                },
+            ),
+            &AccountState::Frozen,
+        )?;
+    }
+    token_interface::initialize_mint2(
+        CpiContext::new(token_program_info.clone(), InitializeMint2 { mint: mint_info.clone() }),
+        ctx.accounts.registry_config.credit_decimals,
+        &registry_info.key(),
+        Some(&registry_info.key()),
+    )?;
+
+    let fields = credit_metadata_fields(project, vintage_year, &methodology, export_authorized);
+    let metadata = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey::try_from(Some(registry_info.key()))?,
+        mint: mint_info.key(),
+        name: format!(
+            "{} Carbon Credit {}{}",
+            project.project_id,
+            vintage_year,
+            if export_authorized { "" } else { " (Domestic)" }
+        ),
+        symbol: CREDIT_SYMBOL.to_string(),
+        uri: format!("ipfs://{}", project.ipfs_cid),
+        additional_metadata: fields.clone(),
+    };
+    fund_metadata_space(
+        &mint_info,
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        0,
+        &metadata,
+    )?;
+
+    let seeds = &[b"registry_v3".as_ref(), &[ctx.accounts.registry.bump]];
+    let signer = &[&seeds[..]];
+
+    token_interface::token_metadata_initialize(
+        CpiContext::new_with_signer(
+            token_program_info.clone(),
+            TokenMetadataInitialize {
+                program_id: token_program_info.clone(),
+                metadata: mint_info.clone(),
+                update_authority: registry_info.clone(),
+                mint_authority: registry_info.clone(),
+                mint: mint_info.clone(),
+            },
+            signer,
+        ),
+        metadata.name,
+        metadata.symbol,
+        metadata.uri,
+    )?;
+
+    for (key, value) in fields {
+        token_interface::token_metadata_update_field(
+            CpiContext::new_with_signer(
+                token_program_info.clone(),
+                TokenMetadataUpdateField {
+                    program_id: token_program_info.clone(),
+                    metadata: mint_info.clone(),
+                    update_authority: registry_info.clone(),
+                },
+                signer,
+            ),
+            Field::Key(key),
+            value,
+        )?;
+    }
+
+    let info = &mut ctx.accounts.credit_mint_info;
+    info.mint = mint_info.key();
+    info.project = project.key();
+    info.project_id = project.project_id.clone();
+    info.vintage_year = vintage_year;
+    info.export_authorized = export_authorized;
+    info.country_code = project.location.country_code.clone();
+    info.serial_prefix = format!("{}-{}-{}", project.location.country_code, project.project_id, vintage_year);
+    info.serials_issued = 0;
+    info.serials_retired = 0;
+    info.issuances = 0;
+    info.buffer_held = 0;
+    info.created_at = Clock::get()?.unix_timestamp;
+    info.bump = ctx.bumps.credit_mint_info;
+
+    msg!(
+        "🪙 Credit mint {} created for {} vintage {} (export authorized: {})",
+        info.mint,
+        project.project_id,
+        vintage_year,
+        export_authorized
+    );
+    Ok(())
+}
+
+/// Sets a key/value field in a credit mint's TokenMetadata, e.g. when the
+/// project's compliance status changes. Registry admin only.
+pub fn update_credit_mint_metadata(
+    ctx: Context<UpdateCreditMintMetadata>,
+    key: String,
+    value: String,
+) -> Result<()> {
+    require!(
+        !key.is_empty()
+            && key.len() <= CreditMintInfo::MAX_METADATA_KEY_LEN
+            && value.len() <= CreditMintInfo::MAX_METADATA_VALUE_LEN,
+        ErrorCode::InvalidMetadataField
+    );
+
+    let mint_info = ctx.accounts.credit_mint.to_account_info();
+    let registry_info = ctx.accounts.registry.to_account_info();
+    let token_program_info = ctx.accounts.token_program.to_account_info();
+
+    let (current_tlv_len, mut metadata) = {
+        let data = mint_info.try_borrow_data()?;
+        let state = StateWithExtensions::<Token2022Mint>::unpack(&data)?;
+        let metadata = state.get_variable_len_extension::<TokenMetadata>()?;
+        (metadata.tlv_size_of()?, metadata)
+    };
+    metadata.update(Field::Key(key.clone()), value.clone());
+    fund_metadata_space(
+        &mint_info,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        current_tlv_len,
+        &metadata,
+    )?;
+
+    let seeds = &[b"registry_v3".as_ref(), &[ctx.accounts.registry.bump]];
+    let signer = &[&seeds[..]];
+
+    token_interface::token_metadata_update_field(
+        CpiContext::new_with_signer(
+            token_program_info.clone(),
+            TokenMetadataUpdateField {
+                program_id: token_program_info,
+                metadata: mint_info,
+                update_authority: registry_info,
+            },
+            signer,
+        ),
+        Field::Key(key.clone()),
+        value.clone(),
+    )?;
+
+    msg!("🏷️ Credit mint {} metadata {} = {}", ctx.accounts.credit_mint.key(), key, value);
+    Ok(())
+}
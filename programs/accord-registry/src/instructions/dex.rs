@@ -1,7 +1,11 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{self, MintTo, Burn, TransferChecked};
 
-use crate::instructions::{contexts::*, errors::ErrorCode};
+use crate::instructions::{
+    contexts::*,
+    credit_mints::{check_credit_recipient, thaw_if_frozen},
+    errors::ErrorCode,
+};
 
 pub fn initialize_pool(
     ctx: Context<InitializePool>,
@@ -19,6 +23,17 @@ pub fn initialize_pool(
     pool.total_liquidity = 0;
     pool.bump = ctx.bumps.pool;
 
+    // The credit vault of a domestic-only mint starts frozen; traders are checked on withdrawal
+    if !ctx.accounts.credit_mint_info.export_authorized {
+        thaw_if_frozen(
+            &ctx.accounts.credit_vault.to_account_info(),
+            &ctx.accounts.credit_mint.to_account_info(),
+            &ctx.accounts.registry.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.registry.bump,
+        )?;
+    }
+
     msg!("Liquidity Pool initialized for Credit/Quote pair");
 
     Ok(())
@@ -36,17 +51,14 @@ pub fn add_liquidity(
     let current_credit_reserve = ctx.accounts.credit_vault.amount;
     let current_quote_reserve = ctx.accounts.quote_vault.amount;
 
-    let liquidity_to_mint: u64;
-
-    if total_liquidity == 0 {
+    let liquidity_to_mint: u64 = if total_liquidity == 0 {
         // Initial liquidity: sqrt(x * y)
         // Adjust for decimals if needed, but assuming standard logic
         let product = (credit_amount as u128)
             .checked_mul(quote_amount as u128)
             .ok_or(ErrorCode::MathOverflow)?;
-        liquidity_to_mint = (product as f64).sqrt() as u64;
-        
         // Lock minimum liquidity could be here, but skipping for simplicity
+        (product as f64).sqrt() as u64
     } else {
         // Subsequent liquidity: min(x / reserve_x * total_lp, y / reserve_y * total_lp)
         // This enforces price ratio maintenance
@@ -62,8 +74,8 @@ pub fn add_liquidity(
             .checked_div(current_quote_reserve as u128)
             .unwrap_or(0);
             
-        liquidity_to_mint = std::cmp::min(credit_share, quote_share) as u64;
-    }
+        std::cmp::min(credit_share, quote_share) as u64
+    };
 
     require!(liquidity_to_mint > 0, ErrorCode::LiquidityZero);
     
@@ -131,6 +143,7 @@ pub fn remove_liquidity(
 
     require!(lp_amount > 0, ErrorCode::LiquidityZero);
     require!(lp_amount <= ctx.accounts.user_lp_account.amount, ErrorCode::InsufficientFunds);
+    check_credit_recipient(&ctx.accounts.credit_mint_info, ctx.accounts.recipient_jurisdiction.as_ref())?;
 
     // Calculate amounts to return
     let credit_amount = (lp_amount as u128)
@@ -206,6 +219,9 @@ pub fn swap(
     let pool = &mut ctx.accounts.pool;
     
     let is_credit_input = ctx.accounts.user_source_account.mint == pool.credit_mint;
+    if !is_credit_input {
+        check_credit_recipient(&ctx.accounts.credit_mint_info, ctx.accounts.recipient_jurisdiction.as_ref())?;
+    }
     
     // Determine Reserves
     let (input_reserve, output_reserve, input_vault, output_vault) = if is_credit_input {
@@ -0,0 +1,176 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::models::*;
+use crate::instructions::{
+    contexts::*,
+    errors::ErrorCode,
+    escrow::{pay_from_escrow, EscrowTokenAccounts, VerificationFeePaid},
+    lifecycle::transition_status,
+    verifier_stake::move_lamports,
+};
+
+/// Opens the post-verification challenge window. Called whenever a project
+/// becomes Verified.
+pub fn start_challenge_window(project: &mut Project, config: &RegistryConfig) -> Result<()> {
+    project.challenge_ends_at = Clock::get()?
+        .unix_timestamp
+        .checked_add(config.challenge_period)
+        .ok_or(ErrorCode::MathOverflow)?;
+    msg!("⚖️ Challenge window open until {}", project.challenge_ends_at);
+    Ok(())
+}
+
+pub fn open_dispute(
+    ctx: Context<OpenDispute>,
+    bond: u64,
+    evidence_cid: String,
+) -> Result<()> {
+    let project = &mut ctx.accounts.project;
+    let dispute = &mut ctx.accounts.dispute;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        matches!(
+            project.verification_status,
+            VerificationStatus::Verified | VerificationStatus::Monitoring
+        ),
+        ErrorCode::ProjectNotVerified
+    );
+    require!(now < project.challenge_ends_at, ErrorCode::ChallengePeriodClosed);
+    require!(project.active_dispute.is_none(), ErrorCode::DisputeAlreadyOpen);
+    require!(
+        bond >= ctx.accounts.registry_config.min_dispute_bond,
+        ErrorCode::InsufficientDisputeBond
+    );
+    require!(
+        !evidence_cid.is_empty() && evidence_cid.len() <= Project::CID_LEN,
+        ErrorCode::InvalidIpfsCid
+    );
+
+    // Bond is held by the dispute PDA until resolution
+    let cpi_context = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        system_program::Transfer {
+            from: ctx.accounts.challenger.to_account_info(),
+            to: dispute.to_account_info(),
+        },
+    );
+    system_program::transfer(cpi_context, bond)?;
+
+    dispute.project = project.key();
+    dispute.challenger = ctx.accounts.challenger.key();
+    dispute.index = project.disputes;
+    dispute.bond = bond;
+    dispute.evidence_cid = evidence_cid;
+    dispute.status = DisputeStatus::Open;
+    dispute.opened_at = now;
+    dispute.resolved_at = 0;
+    dispute.bump = ctx.bumps.dispute;
+
+    project.active_dispute = Some(dispute.key());
+    project.disputes += 1;
+
+    msg!("⚖️ Dispute {} opened on project {} by {}", dispute.index, project.project_id, dispute.challenger);
+    msg!("Bond: {} lamports, evidence: {}", bond, dispute.evidence_cid);
+    Ok(())
+}
+
+/// Pays the escrowed verification fee to the verifier once the challenge
+/// window has closed without an open dispute. Permissionless.
+pub fn release_verification_fee(ctx: Context<ReleaseVerificationFee>) -> Result<()> {
+    let project = &ctx.accounts.project;
+    let fee = project.verification_fee_lamports;
+
+    require!(
+        matches!(
+            project.verification_status,
+            VerificationStatus::Verified | VerificationStatus::Monitoring | VerificationStatus::Expired
+        ),
+        ErrorCode::ProjectNotVerified
+    );
+    require!(fee > 0, ErrorCode::NoVerificationFee);
+    require!(project.active_dispute.is_none(), ErrorCode::ProjectDisputed);
+    require!(
+        Clock::get()?.unix_timestamp >= project.challenge_ends_at,
+        ErrorCode::ChallengePeriodActive
+    );
+
+    let tokens = EscrowTokenAccounts::from_optional(
+        &ctx.accounts.escrow_vault,
+        &ctx.accounts.escrow_mint,
+        &ctx.accounts.recipient_token_account,
+        &ctx.accounts.token_program,
+    );
+    let verifier = ctx.accounts.verifier.to_account_info();
+    let project = &mut ctx.accounts.project;
+    pay_from_escrow(project, fee, &verifier, tokens)?;
+    project.verification_fee_lamports = 0;
+
+    msg!("Verification Fee Released: {} lamports", fee);
+    emit!(VerificationFeePaid {
+        project: project.key(),
+        verifier: verifier.key(),
+        amount: fee,
+        escrow_mint: project.escrow_mint,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+/// Applies a governance decision on an open dispute.
+/// - Upheld: the bond is slashed to `treasury` and the verification stands
+/// - Overturned: the bond is refunded, the project is Rejected and the escrowed
+///   verification fee is returned to `owner` (lamports or stablecoin)
+#[allow(clippy::too_many_arguments)]
+pub fn resolve_dispute<'info>(
+    dispute: &mut Account<'info, VerificationDispute>,
+    project: &mut Account<'info, Project>,
+    challenger: &AccountInfo<'info>,
+    treasury: &AccountInfo<'info>,
+    owner: Option<&AccountInfo<'info>>,
+    tokens: Option<EscrowTokenAccounts<'_, 'info>>,
+    uphold: bool,
+    by: Pubkey,
+) -> Result<()> {
+    require!(dispute.status == DisputeStatus::Open, ErrorCode::DisputeNotOpen);
+    require!(dispute.project == project.key(), ErrorCode::DisputeAccountMismatch);
+    require!(project.active_dispute == Some(dispute.key()), ErrorCode::DisputeAccountMismatch);
+    require!(dispute.challenger == challenger.key(), ErrorCode::DisputeAccountMismatch);
+
+    let bond = dispute.bond;
+    if uphold {
+        move_lamports(&dispute.to_account_info(), treasury, bond)?;
+        dispute.status = DisputeStatus::Upheld;
+        msg!("⚖️ Dispute {} upheld: verification stands, {} lamports slashed", dispute.index, bond);
+    } else {
+        move_lamports(&dispute.to_account_info(), challenger, bond)?;
+
+        let fee = project.verification_fee_lamports;
+        if fee > 0 {
+            let owner = owner.ok_or(ErrorCode::MissingEscrowRefundAccount)?;
+            require!(owner.key() == project.owner, ErrorCode::DisputeAccountMismatch);
+            if let Some(tokens) = tokens.as_ref() {
+                let (vault, _) = Pubkey::find_program_address(
+                    &[b"escrow_vault", project.key().as_ref()],
+                    &crate::ID,
+                );
+                require!(tokens.vault.key() == vault, ErrorCode::MissingEscrowTokenAccounts);
+                require!(tokens.recipient.owner == project.owner, ErrorCode::MissingEscrowTokenAccounts);
+            }
+            pay_from_escrow(project, fee, owner, tokens)?;
+            project.verification_fee_lamports = 0;
+            project.escrow_mint = None;
+            msg!("↩️ Escrowed fee of {} refunded to owner of {}", fee, project.project_id);
+        }
+
+        transition_status(project, VerificationStatus::Rejected, by)?;
+        project.challenge_ends_at = 0;
+        dispute.status = DisputeStatus::Overturned;
+        msg!("⚖️ Dispute {} overturned: project {} rejected, bond refunded", dispute.index, project.project_id);
+    }
+
+    dispute.bond = 0;
+    dispute.resolved_at = Clock::get()?.unix_timestamp;
+    project.active_dispute = None;
+    Ok(())
+}
@@ -0,0 +1,215 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{program::{invoke, invoke_signed}, system_instruction};
+use h3o::{CellIndex, Resolution};
+use crate::models::*;
+use crate::instructions::errors::ErrorCode;
+use crate::instructions::register_project::format_h3_cells;
+
+/// Shards are keyed by the ancestor this many resolutions above the project cells,
+/// so every shard holds at most 7^3 = 343 cells regardless of the cell resolution.
+pub const SHARD_RESOLUTION_OFFSET: u8 = 3;
+
+/// Returns the coarse parent cell that owns `cell` in the sharded registry,
+/// together with the cell's child position (bit index) inside that shard.
+pub fn shard_location(cell: u64) -> Result<(u64, usize)> {
+    let cell = CellIndex::try_from(cell).map_err(|_| ErrorCode::InvalidCoordinates)?;
+    let parent_resolution = u8::from(cell.resolution())
+        .checked_sub(SHARD_RESOLUTION_OFFSET)
+        .and_then(|res| Resolution::try_from(res).ok())
+        .ok_or(ErrorCode::InvalidCoordinates)?;
+
+    let parent = cell.parent(parent_resolution).ok_or(ErrorCode::InvalidCoordinates)?;
+    let position = cell
+        .child_position(parent_resolution)
+        .ok_or(ErrorCode::InvalidCoordinates)?;
+
+    Ok((parent.into(), position as usize))
+}
+
+/// Groups cells by shard, ordered by parent cell (the order shard accounts must be passed in).
+pub fn group_cells_by_shard(cells: &[u64]) -> Result<BTreeMap<u64, Vec<(u64, usize)>>> {
+    let mut shards: BTreeMap<u64, Vec<(u64, usize)>> = BTreeMap::new();
+    for cell in cells {
+        let (parent, position) = shard_location(*cell)?;
+        shards.entry(parent).or_default().push((*cell, position));
+    }
+    Ok(shards)
+}
+
+pub fn shard_address(parent_cell: u64, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"h3_shard", &parent_cell.to_le_bytes()], program_id)
+}
+
+/// Creates a shard PDA owned by this program and writes its initial state.
+fn create_shard<'info>(
+    program_id: &Pubkey,
+    shard_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    parent_cell: u64,
+    bump: u8,
+) -> Result<()> {
+    let space = 8 + DoubleCountingShard::LEN;
+    let lamports = Rent::get()?.minimum_balance(space);
+    let parent_bytes = parent_cell.to_le_bytes();
+    let seeds = &[b"h3_shard".as_ref(), parent_bytes.as_ref(), &[bump]];
+    let signer = &[&seeds[..]];
+
+    let current_lamports = shard_info.lamports();
+    if current_lamports == 0 {
+        invoke_signed(
+            &system_instruction::create_account(payer.key, shard_info.key, lamports, space as u64, program_id),
+            &[payer.clone(), shard_info.clone(), system_program.clone()],
+            signer,
+        )?;
+    } else {
+        // Someone pre-funded the address; top up, allocate and assign instead
+        let top_up = lamports.saturating_sub(current_lamports);
+        if top_up > 0 {
+            invoke(
+                &system_instruction::transfer(payer.key, shard_info.key, top_up),
+                &[payer.clone(), shard_info.clone(), system_program.clone()],
+            )?;
+        }
+        invoke_signed(
+            &system_instruction::allocate(shard_info.key, space as u64),
+            &[shard_info.clone(), system_program.clone()],
+            signer,
+        )?;
+        invoke_signed(
+            &system_instruction::assign(shard_info.key, program_id),
+            &[shard_info.clone(), system_program.clone()],
+            signer,
+        )?;
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let shard = DoubleCountingShard {
+        parent_cell,
+        occupancy: [0; DoubleCountingShard::BITMAP_LEN],
+        occupied_cells: 0,
+        peak_occupied_cells: 0,
+        total_reservations: 0,
+        total_releases: 0,
+        created_at: now,
+        last_updated: now,
+        bump,
+    };
+    let mut data = shard_info.try_borrow_mut_data()?;
+    shard.try_serialize(&mut &mut data[..])?;
+
+    msg!("🧩 Created H3 shard {}", format_h3_cells(&[parent_cell]).join(""));
+    Ok(())
+}
+
+/// Releases `released` and reserves `reserved` in the sharded double-counting registry.
+///
+/// `shard_infos` must contain exactly one shard PDA per distinct parent cell
+/// touched by either list, ordered by ascending parent cell index. Shards that
+/// receive reservations are created on demand and paid for by `payer`. Releases
+/// are applied first, so a project can keep cells it already holds. Fails with
+/// `H3CellsAlreadyRegistered` (after logging every conflicting cell) if any
+/// reserved cell is taken by another project.
+pub fn update_cells<'info>(
+    program_id: &Pubkey,
+    registry: &mut DoubleCountingRegistry,
+    shard_infos: &[AccountInfo<'info>],
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    released: &[u64],
+    reserved: &[u64],
+) -> Result<()> {
+    let released_by_shard = group_cells_by_shard(released)?;
+    let reserved_by_shard = group_cells_by_shard(reserved)?;
+    let parents: BTreeSet<u64> = released_by_shard
+        .keys()
+        .chain(reserved_by_shard.keys())
+        .copied()
+        .collect();
+    require!(shard_infos.len() == parents.len(), ErrorCode::InvalidShardAccount);
+
+    let mut shards = Vec::with_capacity(parents.len());
+    for (parent_cell, shard_info) in parents.iter().zip(shard_infos.iter()) {
+        let (expected, bump) = shard_address(*parent_cell, program_id);
+        require_keys_eq!(shard_info.key(), expected, ErrorCode::InvalidShardAccount);
+
+        if shard_info.data_is_empty() {
+            require!(reserved_by_shard.contains_key(parent_cell), ErrorCode::InvalidShardAccount);
+            create_shard(program_id, shard_info, payer, system_program, *parent_cell, bump)?;
+            registry.total_shards += 1;
+        }
+
+        require_keys_eq!(*shard_info.owner, *program_id, ErrorCode::InvalidShardAccount);
+        let shard = DoubleCountingShard::try_deserialize(&mut &shard_info.try_borrow_data()?[..])?;
+        require!(shard.parent_cell == *parent_cell, ErrorCode::InvalidShardAccount);
+        shards.push(shard);
+    }
+
+    let occupied_before: u64 = shards.iter().map(|shard| shard.occupied_cells as u64).sum();
+
+    for (parent_cell, shard) in parents.iter().zip(shards.iter_mut()) {
+        if let Some(entries) = released_by_shard.get(parent_cell) {
+            for (_, position) in entries {
+                shard.release(*position);
+            }
+            shard.total_releases += entries.len() as u64;
+        }
+    }
+
+    // Check every shard before writing anything so the error lists all conflicts
+    let conflicting_cells: Vec<u64> = parents
+        .iter()
+        .zip(shards.iter())
+        .flat_map(|(parent_cell, shard)| {
+            reserved_by_shard
+                .get(parent_cell)
+                .into_iter()
+                .flatten()
+                .filter(|(_, position)| shard.is_occupied(*position))
+                .map(|(cell, _)| *cell)
+        })
+        .collect();
+
+    if !conflicting_cells.is_empty() {
+        msg!(
+            "❌ Boundary overlaps {} registered H3 cell(s): {:?}",
+            conflicting_cells.len(),
+            format_h3_cells(&conflicting_cells)
+        );
+        return Err(ErrorCode::H3CellsAlreadyRegistered.into());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let mut occupied_after: u64 = 0;
+    for ((parent_cell, mut shard), shard_info) in parents.iter().zip(shards).zip(shard_infos.iter()) {
+        if let Some(entries) = reserved_by_shard.get(parent_cell) {
+            for (_, position) in entries {
+                shard.occupy(*position);
+            }
+            shard.total_reservations += entries.len() as u64;
+        }
+        shard.last_updated = now;
+        occupied_after += shard.occupied_cells as u64;
+
+        let mut data = shard_info.try_borrow_mut_data()?;
+        shard.try_serialize(&mut &mut data[..])?;
+    }
+
+    registry.total_cells = (registry.total_cells + occupied_after).saturating_sub(occupied_before);
+
+    Ok(())
+}
+
+/// Reserves `cells` for a newly registered project. See [`update_cells`].
+pub fn reserve_cells<'info>(
+    program_id: &Pubkey,
+    registry: &mut DoubleCountingRegistry,
+    shard_infos: &[AccountInfo<'info>],
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    cells: &[u64],
+) -> Result<()> {
+    update_cells(program_id, registry, shard_infos, payer, system_program, &[], cells)
+}
@@ -48,7 +48,7 @@ pub enum ErrorCode {
     MissingRegistryId,
     #[msg("Project ID must match ICM Registry ID")]
     RegistryIdMismatch,
-    #[msg("Verification fee is below minimum required (0.1 SOL)")]
+    #[msg("Verification fee is below the configured minimum")]
     InsufficientVerificationFee,
     #[msg("Invalid geographic coordinates")]
     InvalidCoordinates,
@@ -56,4 +56,146 @@ pub enum ErrorCode {
     UnauthorizedDataSubmission,
     #[msg("Monitoring data is stale or outdated")]
     StaleMonitoringData,
-}
\ No newline at end of file
+    #[msg("Project boundary overlaps H3 cells already registered to another project")]
+    H3CellsAlreadyRegistered,
+    #[msg("Project boundary covers too many H3 cells")]
+    ProjectBoundaryTooLarge,
+    #[msg("Missing, misordered or invalid H3 shard account")]
+    InvalidShardAccount,
+    #[msg("Project status transition is not allowed")]
+    InvalidStatusTransition,
+    #[msg("Registration must be preceded by an ed25519 verification of the government signature")]
+    MissingComplianceSignature,
+    #[msg("Compliance ID signature does not match the government authority attestation")]
+    InvalidComplianceSignature,
+    #[msg("Amendment does not change any project field")]
+    EmptyAmendment,
+    #[msg("IPFS CID is empty or too long")]
+    InvalidIpfsCid,
+    #[msg("Only AwaitingAudit or Rejected projects can be withdrawn")]
+    ProjectNotWithdrawable,
+    #[msg("Project has minted credits and cannot be closed")]
+    ProjectHasMintedCredits,
+    #[msg("Project is already owned by this account")]
+    InvalidNewOwner,
+    #[msg("No ownership transfer is pending for this account")]
+    NoPendingOwnershipTransfer,
+    #[msg("Registry config value is out of range")]
+    InvalidConfigValue,
+    #[msg("Sector data does not match the project sector")]
+    SectorDataMismatch,
+    #[msg("Sector data is missing or out of range")]
+    InvalidSectorData,
+    #[msg("Measurement is out of range")]
+    InvalidMeasurement,
+    #[msg("Verification round quorum or outlier threshold is invalid")]
+    InvalidVerificationRound,
+    #[msg("Verification round is already finalized")]
+    VerificationRoundFinalized,
+    #[msg("Verifier has already attested in this round")]
+    DuplicateAttestation,
+    #[msg("Verification round has no room for more attestations")]
+    TooManyAttestations,
+    #[msg("Not enough consistent attestations to reach quorum")]
+    QuorumNotReached,
+    #[msg("A required verifier type has not attested")]
+    MissingRequiredVerifierType,
+    #[msg("Verifier stake is below the project's minimum")]
+    InsufficientVerifierStake,
+    #[msg("Stake amount must be positive and within the available balance")]
+    InvalidStakeAmount,
+    #[msg("Stake is still unbonding")]
+    StakeStillUnbonding,
+    #[msg("The challenge period for this verification has closed")]
+    ChallengePeriodClosed,
+    #[msg("The challenge period is still running")]
+    ChallengePeriodActive,
+    #[msg("Project already has an open dispute")]
+    DisputeAlreadyOpen,
+    #[msg("Project has an open dispute")]
+    ProjectDisputed,
+    #[msg("Dispute bond is below the configured minimum")]
+    InsufficientDisputeBond,
+    #[msg("Dispute is not open")]
+    DisputeNotOpen,
+    #[msg("Project or challenger does not match the dispute")]
+    DisputeAccountMismatch,
+    #[msg("No verification fee is held for release")]
+    NoVerificationFee,
+    #[msg("Verifier is not specialized in the project's sector")]
+    VerifierNotSpecialized,
+    #[msg("Verifier cannot audit their own project")]
+    VerifierIsProjectOwner,
+    #[msg("Verifier audited this owner too recently")]
+    AuditCooldownActive,
+    #[msg("Credentials or specializations are empty, too long or already expired")]
+    InvalidCredentials,
+    #[msg("Suspension expiry must be in the future and the reason within limits")]
+    InvalidSuspension,
+    #[msg("Verifier is not suspended")]
+    VerifierNotSuspended,
+    #[msg("Verifier is suspended")]
+    VerifierSuspended,
+    #[msg("Withdraw all verifier stake before deregistering")]
+    VerifierStillStaked,
+    #[msg("Unauthorized: Only admin can perform this action")]
+    UnauthorizedAdmin,
+    #[msg("Rejection reason is empty or too long")]
+    InvalidRejectionReason,
+    #[msg("Project verification has expired; renew it first")]
+    VerificationExpired,
+    #[msg("Project verification has not expired yet")]
+    VerificationNotExpired,
+    #[msg("Release the previous verification fee before renewing")]
+    UnreleasedVerificationFee,
+    #[msg("The audit deadline has not passed yet")]
+    AuditDeadlineNotReached,
+    #[msg("Verifier is not assigned to this verification round")]
+    NotAssignedVerifier,
+    #[msg("No verification fee is claimable by this verifier")]
+    NoClaimableFee,
+    #[msg("Escrow vault, mint, recipient token account and token program are required for stablecoin escrow")]
+    MissingEscrowTokenAccounts,
+    #[msg("Mint is not the accepted escrow stablecoin")]
+    InvalidEscrowMint,
+    #[msg("Audit job is not open")]
+    AuditJobNotOpen,
+    #[msg("Bid price exceeds the job's fee budget or timeline is out of range")]
+    InvalidAuditBid,
+    #[msg("Monitoring period is empty, in the future or overlaps a previous batch")]
+    InvalidMonitoringPeriod,
+    #[msg("Issuance batch is not pending verification")]
+    BatchNotPending,
+    #[msg("Issuance batch has not been verified")]
+    BatchNotVerified,
+    #[msg("Credit mint was not issued by the registry for this project and vintage")]
+    CreditMintMismatch,
+    #[msg("Credit metadata key or value is empty or too long")]
+    InvalidMetadataField,
+    #[msg("Issued and retired amounts must be whole tonnes")]
+    FractionalTonnes,
+    #[msg("Retirement ID is empty or too long")]
+    InvalidRetirementId,
+    #[msg("Buffer rate exceeds the maximum")]
+    InvalidBufferRate,
+    #[msg("Not enough credits held in the buffer pool")]
+    InsufficientBufferCredits,
+    #[msg("The project's permanence period has not ended")]
+    PermanencePeriodActive,
+    #[msg("Export-authorized credits require an issued Letter of Authorization")]
+    LoaNotIssued,
+    #[msg("Issuance would exceed the project's authorized export limit")]
+    ExceedsExportLimit,
+    #[msg("Domestic-only credits can only go to wallets attested in the host country")]
+    DomesticOnlyCredits,
+    #[msg("Verified tonnage can only change through re-verification")]
+    VerifiedTonnageLocked,
+    #[msg("H3 resolution cannot change while cells are reserved at the current resolution")]
+    H3ResolutionLocked,
+    #[msg("Overturning a dispute requires the project owner to refund the escrowed fee")]
+    MissingEscrowRefundAccount,
+    #[msg("Escrow cannot be reclaimed while a verification round has pending attestations")]
+    VerificationInProgress,
+    #[msg("Vintage year must fall between the project's establishment and the current year")]
+    InvalidVintageYear,
+}
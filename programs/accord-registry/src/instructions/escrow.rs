@@ -0,0 +1,251 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::models::*;
+use crate::instructions::{
+    contexts::*,
+    errors::ErrorCode,
+    lifecycle::transition_status,
+    verifier_stake::move_lamports,
+    verify_project::check_verifier_stake,
+};
+
+/// Emitted when escrowed verification fees are actually paid to a verifier
+#[event]
+pub struct VerificationFeePaid {
+    pub project: Pubkey,
+    pub verifier: Pubkey,
+    pub amount: u64,
+    pub escrow_mint: Option<Pubkey>, // None for lamports
+    pub timestamp: i64,
+}
+
+/// Token accounts needed to pay out of a stablecoin escrow
+pub struct EscrowTokenAccounts<'a, 'info> {
+    pub vault: &'a InterfaceAccount<'info, TokenAccount>,
+    pub mint: &'a InterfaceAccount<'info, Mint>,
+    pub recipient: &'a InterfaceAccount<'info, TokenAccount>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+}
+
+impl<'a, 'info> EscrowTokenAccounts<'a, 'info> {
+    /// Collects the optional escrow accounts of a payout context; `None` unless all are present
+    pub fn from_optional(
+        vault: &'a Option<InterfaceAccount<'info, TokenAccount>>,
+        mint: &'a Option<InterfaceAccount<'info, Mint>>,
+        recipient: &'a Option<InterfaceAccount<'info, TokenAccount>>,
+        token_program: &'a Option<Interface<'info, TokenInterface>>,
+    ) -> Option<Self> {
+        Some(Self {
+            vault: vault.as_ref()?,
+            mint: mint.as_ref()?,
+            recipient: recipient.as_ref()?,
+            token_program: token_program.as_ref()?,
+        })
+    }
+}
+
+/// Pays `amount` out of the project's audit escrow: lamports to `recipient`,
+/// or stablecoin to `tokens.recipient` when the escrow is held in `escrow_mint`.
+/// The caller adjusts `verification_fee_lamports`.
+pub fn pay_from_escrow<'info>(
+    project: &mut Account<'info, Project>,
+    amount: u64,
+    recipient: &AccountInfo<'info>,
+    tokens: Option<EscrowTokenAccounts<'_, 'info>>,
+) -> Result<()> {
+    match project.escrow_mint {
+        None => move_lamports(&project.to_account_info(), recipient, amount)?,
+        Some(escrow_mint) => {
+            let tokens = tokens.ok_or(ErrorCode::MissingEscrowTokenAccounts)?;
+            require!(tokens.mint.key() == escrow_mint, ErrorCode::InvalidEscrowMint);
+
+            let project_id = project.project_id.clone();
+            let seeds = &[b"project".as_ref(), project_id.as_bytes(), &[project.bump]];
+            let signer_seeds = &[&seeds[..]];
+
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    tokens.token_program.to_account_info(),
+                    TransferChecked {
+                        from: tokens.vault.to_account_info(),
+                        mint: tokens.mint.to_account_info(),
+                        to: tokens.recipient.to_account_info(),
+                        authority: project.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+                tokens.mint.decimals,
+            )?;
+        }
+    }
+
+    project.audit_escrow_balance = project.audit_escrow_balance.saturating_sub(amount);
+    Ok(())
+}
+
+/// Sets the deadline after which an unaudited escrow can be reclaimed.
+pub fn start_audit_deadline(project: &mut Project, config: &RegistryConfig) -> Result<()> {
+    project.audit_deadline = Clock::get()?
+        .unix_timestamp
+        .checked_add(config.audit_timeout)
+        .ok_or(ErrorCode::MathOverflow)?;
+    Ok(())
+}
+
+/// Same as `initialize_verification`, but escrows the fee in the configured stablecoin.
+pub fn initialize_stable_verification(
+    ctx: Context<InitializeStableVerification>,
+    fee_amount: u64,
+) -> Result<()> {
+    let project = &mut ctx.accounts.project;
+    let verifier = &ctx.accounts.verifier;
+
+    require!(
+        fee_amount >= ctx.accounts.registry_config.min_stable_verification_fee,
+        ErrorCode::InsufficientVerificationFee
+    );
+    // Lamport and stablecoin balances never share the escrow counters
+    require!(
+        project.verification_fee_lamports == 0 && project.audit_escrow_balance == 0,
+        ErrorCode::UnreleasedVerificationFee
+    );
+    check_verifier_stake(&ctx.accounts.registry_config, project, ctx.accounts.verifier_stake.as_ref())?;
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.owner_token_account.to_account_info(),
+                mint: ctx.accounts.escrow_mint.to_account_info(),
+                to: ctx.accounts.escrow_vault.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        fee_amount,
+        ctx.accounts.escrow_mint.decimals,
+    )?;
+
+    project.verifier = Some(verifier.key());
+    project.verification_fee_lamports = fee_amount;
+    project.audit_escrow_balance = fee_amount;
+    project.escrow_mint = Some(ctx.accounts.escrow_mint.key());
+    start_audit_deadline(project, &ctx.accounts.registry_config)?;
+    transition_status(project, VerificationStatus::AwaitingAudit, ctx.accounts.owner.key())?;
+
+    msg!("Verification Escrow Funded: {} of mint {}", fee_amount, ctx.accounts.escrow_mint.key());
+    msg!("Assigned Verifier: {}", verifier.key());
+
+    Ok(())
+}
+
+/// Returns an unused audit escrow to the owner once the audit deadline has passed.
+/// Only an AwaitingAudit project with no decision and no pending attestations qualifies.
+pub fn reclaim_verification_escrow(ctx: Context<ReclaimVerificationEscrow>) -> Result<()> {
+    let fee = ctx.accounts.project.verification_fee_lamports;
+    let project = &ctx.accounts.project;
+
+    require!(
+        project.verification_status == VerificationStatus::AwaitingAudit,
+        ErrorCode::ProjectAlreadyProcessed
+    );
+    require!(fee > 0, ErrorCode::NoVerificationFee);
+    // Verifiers who already attested in the latest round are owed the escrow
+    if project.verification_rounds > 0 {
+        let round = ctx
+            .accounts
+            .verification_round
+            .as_ref()
+            .ok_or(ErrorCode::InvalidVerificationRound)?;
+        require!(
+            round.finalized || round.attestations.is_empty(),
+            ErrorCode::VerificationInProgress
+        );
+    }
+    require!(
+        project.audit_deadline != 0 && Clock::get()?.unix_timestamp >= project.audit_deadline,
+        ErrorCode::AuditDeadlineNotReached
+    );
+
+    let tokens = EscrowTokenAccounts::from_optional(
+        &ctx.accounts.escrow_vault,
+        &ctx.accounts.escrow_mint,
+        &ctx.accounts.recipient_token_account,
+        &ctx.accounts.token_program,
+    );
+    let owner = ctx.accounts.owner.to_account_info();
+    let project = &mut ctx.accounts.project;
+    pay_from_escrow(project, fee, &owner, tokens)?;
+
+    project.verification_fee_lamports = 0;
+    project.verifier = None;
+    project.escrow_mint = None;
+    project.audit_deadline = 0;
+
+    msg!("↩️ Audit escrow of {} reclaimed by owner of {}", fee, project.project_id);
+    Ok(())
+}
+
+/// Pays a verifier its share of a finalized round's escrow after the
+/// challenge window. The pool is split in proportion to accepted (inlier)
+/// attestations; outliers receive nothing and the last claim takes the dust.
+pub fn claim_verification_fee(ctx: Context<ClaimVerificationFee>) -> Result<()> {
+    let verifier_authority = ctx.accounts.verifier_authority.key();
+    let round = &mut ctx.accounts.verification_round;
+    let project = &ctx.accounts.project;
+
+    require!(round.finalized, ErrorCode::InvalidVerificationRound);
+    require!(
+        matches!(
+            project.verification_status,
+            VerificationStatus::Verified | VerificationStatus::Monitoring | VerificationStatus::Expired
+        ),
+        ErrorCode::ProjectNotVerified
+    );
+    require!(project.active_dispute.is_none(), ErrorCode::ProjectDisputed);
+    require!(
+        Clock::get()?.unix_timestamp >= project.challenge_ends_at,
+        ErrorCode::ChallengePeriodActive
+    );
+
+    let inliers = round.attestations.iter().filter(|a| !a.is_outlier).count() as u64;
+    let unclaimed = round.attestations.iter().filter(|a| !a.is_outlier && !a.fee_claimed).count();
+    let index = round
+        .attestations
+        .iter()
+        .position(|a| a.verifier == verifier_authority && !a.is_outlier && !a.fee_claimed)
+        .ok_or(ErrorCode::NoClaimableFee)?;
+
+    let share = if unclaimed == 1 {
+        round.fee_pool - round.fee_paid
+    } else {
+        round.fee_pool / inliers
+    };
+    // Never pay more than the escrow still attributed to this verification
+    let share = share.min(project.verification_fee_lamports);
+    require!(share > 0, ErrorCode::NoClaimableFee);
+
+    round.attestations[index].fee_claimed = true;
+    round.fee_paid += share;
+
+    let tokens = EscrowTokenAccounts::from_optional(
+        &ctx.accounts.escrow_vault,
+        &ctx.accounts.escrow_mint,
+        &ctx.accounts.recipient_token_account,
+        &ctx.accounts.token_program,
+    );
+    let recipient = ctx.accounts.verifier_authority.to_account_info();
+    let project = &mut ctx.accounts.project;
+    pay_from_escrow(project, share, &recipient, tokens)?;
+    project.verification_fee_lamports -= share;
+
+    msg!("💰 Verifier {} claimed {} from round {}", verifier_authority, share, round.round);
+    emit!(VerificationFeePaid {
+        project: project.key(),
+        verifier: verifier_authority,
+        amount: share,
+        escrow_mint: project.escrow_mint,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use crate::models::*;
+use crate::instructions::{
+    contexts::*,
+    errors::ErrorCode,
+    verify_project::{check_verifier_stake, record_eligible_audit},
+};
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// Gregorian (UTC) year of a unix timestamp, using Howard Hinnant's
+/// `civil_from_days` with years starting on March 1
+fn year_of(timestamp: i64) -> i64 {
+    let days = timestamp.div_euclid(SECONDS_PER_DAY) + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    // January and February (shifted months 10 and 11) belong to the next civil year
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    era * 400 + year_of_era + i64::from(shifted_month >= 10)
+}
+
+/// Opens an issuance batch for one monitoring period of a verified project.
+/// Periods must follow each other without overlapping so no reduction is
+/// issued twice.
+pub fn open_issuance_batch(
+    ctx: Context<OpenIssuanceBatch>,
+    period_start: i64,
+    period_end: i64,
+    vintage_year: u16,
+    claimed_tons: u64,
+    report_cid: String,
+) -> Result<()> {
+    let project = &mut ctx.accounts.project;
+    let batch = &mut ctx.accounts.issuance_batch;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        project.verification_status == VerificationStatus::Verified,
+        ErrorCode::ProjectNotVerified
+    );
+    require!(
+        period_start < period_end && period_end <= now && period_start >= project.monitored_until,
+        ErrorCode::InvalidMonitoringPeriod
+    );
+    require!(
+        (year_of(project.establishment_date)..=year_of(now)).contains(&i64::from(vintage_year)),
+        ErrorCode::InvalidVintageYear
+    );
+    require!(claimed_tons > 0, ErrorCode::InvalidCarbonMeasurement);
+    require!(
+        !report_cid.is_empty() && report_cid.len() <= Project::CID_LEN,
+        ErrorCode::InvalidIpfsCid
+    );
+
+    batch.project = project.key();
+    batch.index = project.issuance_batches;
+    batch.period_start = period_start;
+    batch.period_end = period_end;
+    batch.vintage_year = vintage_year;
+    batch.claimed_tons = claimed_tons;
+    batch.verified_tons = 0;
+    batch.tokens_minted = 0;
+    batch.report_cid = report_cid;
+    batch.status = IssuanceBatchStatus::Pending;
+    batch.verified_by = None;
+    batch.verified_at = 0;
+    batch.bump = ctx.bumps.issuance_batch;
+
+    project.issuance_batches += 1;
+    project.monitored_until = period_end;
+
+    msg!(
+        "📦 Issuance batch {} opened for {}: vintage {}, {} tons claimed",
+        batch.index,
+        project.project_id,
+        vintage_year,
+        claimed_tons
+    );
+    Ok(())
+}
+
+/// Verifies a pending batch, fixing the tonnage that may be minted from it.
+/// The project must be Verified, current and undisputed, as for minting.
+pub fn verify_issuance_batch(ctx: Context<VerifyIssuanceBatch>, verified_tons: u64) -> Result<()> {
+    let project = &mut ctx.accounts.project;
+    let batch = &mut ctx.accounts.issuance_batch;
+    let verifier = ctx.accounts.verifier_authority.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(batch.status == IssuanceBatchStatus::Pending, ErrorCode::BatchNotPending);
+    require!(
+        project.verification_status == VerificationStatus::Verified,
+        ErrorCode::ProjectNotVerified
+    );
+    require!(project.active_dispute.is_none(), ErrorCode::ProjectDisputed);
+    require!(project.verification_current(now), ErrorCode::VerificationExpired);
+    require!(
+        verified_tons > 0 && verified_tons <= batch.claimed_tons,
+        ErrorCode::InvalidCarbonMeasurement
+    );
+    check_verifier_stake(&ctx.accounts.registry_config, project, ctx.accounts.verifier_stake.as_ref())?;
+    record_eligible_audit(
+        &ctx.accounts.verifier_node,
+        &mut ctx.accounts.verifier_audit,
+        project,
+        &ctx.accounts.registry_config,
+        verifier,
+        ctx.bumps.verifier_audit,
+    )?;
+
+    batch.verified_tons = verified_tons;
+    batch.status = IssuanceBatchStatus::Verified;
+    batch.verified_by = Some(verifier);
+    batch.verified_at = now;
+
+    ctx.accounts.verification_record.set_inner(VerificationRecord {
+        project: project.key(),
+        index: project.verification_records,
+        verifier,
+        decision: VerificationDecision::BatchVerified,
+        carbon_tons: verified_tons,
+        quality_rating: project.quality_rating,
+        report_cid: batch.report_cid.clone(),
+        reason: String::new(),
+        timestamp: now,
+        fee_paid: 0,
+        bump: ctx.bumps.verification_record,
+    });
+    project.verification_records += 1;
+
+    msg!("✅ Issuance batch {} verified: {} tons (vintage {})", batch.index, verified_tons, batch.vintage_year);
+    Ok(())
+}
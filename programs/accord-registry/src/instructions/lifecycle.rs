@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::models::*;
+use crate::instructions::errors::ErrorCode;
+
+/// Emitted on every project status change
+#[event]
+pub struct ProjectStatusChanged {
+    pub project: Pubkey,
+    pub project_id: String,
+    pub old_status: VerificationStatus,
+    pub new_status: VerificationStatus,
+    pub changed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Moves a project to `new_status` if the lifecycle transition table allows it.
+///
+/// All instructions that change `Project.verification_status` go through here.
+pub fn transition_status(
+    project: &mut Account<Project>,
+    new_status: VerificationStatus,
+    changed_by: Pubkey,
+) -> Result<()> {
+    let old_status = project.verification_status.clone();
+
+    if !old_status.can_transition_to(&new_status) {
+        msg!("❌ Illegal status transition: {:?} -> {:?}", old_status, new_status);
+        return Err(ErrorCode::InvalidStatusTransition.into());
+    }
+
+    project.verification_status = new_status.clone();
+    // A verification decision ends the audit window; the escrow is no longer reclaimable
+    if matches!(new_status, VerificationStatus::Verified | VerificationStatus::Rejected) {
+        project.audit_deadline = 0;
+    }
+
+    msg!("📊 Status: {:?} -> {:?}", old_status, new_status);
+    emit!(ProjectStatusChanged {
+        project: project.key(),
+        project_id: project.project_id.clone(),
+        old_status,
+        new_status,
+        changed_by,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,7 +1,11 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{self, TransferChecked, CloseAccount};
 use crate::models::*;
-use crate::instructions::{contexts::*, errors::ErrorCode};
+use crate::instructions::{
+    contexts::*,
+    credit_mints::{check_credit_recipient, thaw_if_frozen},
+    errors::ErrorCode,
+};
 
 pub fn create_marketplace_listing(
     ctx: Context<CreateMarketplaceListing>,
@@ -17,8 +21,13 @@ pub fn create_marketplace_listing(
     );
 
     require!(
-        project.verification_status == VerificationStatus::Verified,
-        ErrorCode::ProjectNotVerified
+        project.verification_current(Clock::get()?.unix_timestamp),
+        ErrorCode::VerificationExpired
+    );
+
+    require!(
+        listing_data.vintage_year == ctx.accounts.credit_mint_info.vintage_year,
+        ErrorCode::CreditMintMismatch
     );
 
     // Removed specific quantity check against project.available_quantity as it conflates units (tons vs tokens)
@@ -37,6 +46,17 @@ pub fn create_marketplace_listing(
     listing.expiry_date = listing_data.expiry_date;
     listing.is_active = true;
 
+    // The vault of a domestic-only mint starts frozen; buyers are checked on purchase
+    if !ctx.accounts.credit_mint_info.export_authorized {
+        thaw_if_frozen(
+            &ctx.accounts.listing_vault.to_account_info(),
+            &ctx.accounts.credit_mint.to_account_info(),
+            &ctx.accounts.registry.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.registry.bump,
+        )?;
+    }
+
     // Transfer Carbon Credits to Listing Vault
     let cpi_accounts = TransferChecked {
         from: ctx.accounts.seller_credit_account.to_account_info(),
@@ -65,6 +85,7 @@ pub fn buy_marketplace_listing(
     require!(listing.is_active, ErrorCode::InstructionFailed); // Add specific error later
     require!(amount <= listing.quantity_available, ErrorCode::ExceedsAvailableQuantity);
     require!(ctx.accounts.currency_mint.key() == listing.currency_mint, ErrorCode::InstructionFailed);
+    check_credit_recipient(&ctx.accounts.credit_mint_info, ctx.accounts.buyer_jurisdiction.as_ref())?;
 
     // Calculate total cost (Price is per whole token)
     // amount (raw) * price (per whole) / 10^decimals
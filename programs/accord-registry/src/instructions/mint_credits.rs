@@ -7,7 +7,15 @@ use anchor_spl::token_2022::spl_token_2022::{
     instruction as token_instruction_2022,
 };
 use crate::models::*;
-use crate::instructions::{contexts::*, errors::ErrorCode};
+use crate::instructions::{
+    buffer_pool::{buffer_contribution, deposit_buffer},
+    contexts::*,
+    credit_mints::{
+        allocate_serials, check_batch_recipient, check_credit_recipient, credit_scale,
+        record_export_issuance, thaw_if_frozen,
+    },
+    errors::ErrorCode,
+};
 
 pub fn initialize_registry(
     ctx: Context<InitializeRegistry>,
@@ -110,22 +118,58 @@ pub fn mint_verified_credits(
         project.compliance.audit_status == "Approved",
         ErrorCode::ComplianceNotApproved
     );
+    require!(project.active_dispute.is_none(), ErrorCode::ProjectDisputed);
+    require!(
+        project.verification_current(Clock::get()?.unix_timestamp),
+        ErrorCode::VerificationExpired
+    );
 
-    // Ensure we don't exceed the verified carbon tons (1 token = 1 ton)
-    // Scale carbon_tons_estimated to match token precision (6 decimals)
-    let verified_capacity = project.carbon_tons_estimated * 10u64.pow(6);
+    // Ensure we don't exceed the batch's verified tons (1 token = 1 ton)
+    // Scale verified_tons to the precision of the mint being issued from
+    // Buffer credits are withheld on top of the recipient's amount
+    let scale = credit_scale(ctx.accounts.carbon_token_mint.decimals)?;
+    let batch = &mut ctx.accounts.issuance_batch;
+    require!(batch.status == IssuanceBatchStatus::Verified, ErrorCode::BatchNotVerified);
+    let buffer = buffer_contribution(&ctx.accounts.registry_config, project, amount, scale)?;
+    let issued = amount + buffer;
+    let verified_capacity = batch.verified_tons.checked_mul(scale).ok_or(ErrorCode::MathOverflow)?;
     require!(
-        project.tokens_minted + amount <= verified_capacity,
+        batch.tokens_minted + issued <= verified_capacity,
         ErrorCode::ExceedsVerifiedCapacity
     );
 
-    // If LoA is NOT issued, perhaps we mark these tokens as "Domestic Only"?
-    // For now we just mint, but metadata should reflect it.
-    msg!("Minting Compliance Check: OK. LoA Issued: {}", project.compliance.loa_issued);
+    // LoA-backed credits count against the authorized export limit
+    record_export_issuance(
+        project,
+        &ctx.accounts.credit_mint_info,
+        issued,
+        scale,
+    )?;
+    check_credit_recipient(&ctx.accounts.credit_mint_info, ctx.accounts.recipient_jurisdiction.as_ref())?;
+    msg!(
+        "Minting Compliance Check: OK. Export authorized: {}",
+        ctx.accounts.credit_mint_info.export_authorized
+    );
 
     // Get the bump from registry without mutable borrow
     let registry_bump = ctx.accounts.registry.bump;
 
+    // Domestic-only token accounts start frozen; the recipient was just checked
+    if !ctx.accounts.credit_mint_info.export_authorized {
+        for account in [
+            ctx.accounts.recipient_token_account.to_account_info(),
+            ctx.accounts.buffer_vault.to_account_info(),
+        ] {
+            thaw_if_frozen(
+                &account,
+                &ctx.accounts.carbon_token_mint.to_account_info(),
+                &ctx.accounts.registry.to_account_info(),
+                &ctx.accounts.token_program.to_account_info(),
+                registry_bump,
+            )?;
+        }
+    }
+
     // Create the context for the `mint_to` instruction
     let cpi_accounts = MintTo {
         mint: ctx.accounts.carbon_token_mint.to_account_info(),
@@ -144,63 +188,84 @@ pub fn mint_verified_credits(
         amount,
     )?;
 
-    // Update project and registry tracking
+    deposit_buffer(
+        &mut ctx.accounts.credit_mint_info,
+        MintTo {
+            mint: ctx.accounts.carbon_token_mint.to_account_info(),
+            to: ctx.accounts.buffer_vault.to_account_info(),
+            authority: ctx.accounts.registry.to_account_info(),
+        },
+        ctx.accounts.token_program.to_account_info(),
+        registry_bump,
+        buffer,
+    )?;
+
+    // Update batch, project and registry tracking
     let registry = &mut ctx.accounts.registry;
-    project.credits_issued += amount;
-    project.tokens_minted += amount;
-    registry.total_credits_issued += amount;
+    let batch = &mut ctx.accounts.issuance_batch;
+    record_issuance(
+        &mut ctx.accounts.issuance_record,
+        &mut ctx.accounts.credit_mint_info,
+        project.key(),
+        batch.key(),
+        (amount, buffer),
+        scale,
+        ctx.accounts.registry_config.permanence_period,
+        ctx.bumps.issuance_record,
+    )?;
+    batch.tokens_minted += issued;
+    project.credits_issued += issued;
+    project.tokens_minted += issued;
+    registry.total_credits_issued += issued;
     
     msg!(
         "Minted {} carbon credit tokens (Token-2022) for project {}",
         amount,
         project.project_id
     );
+    msg!("Tokens minted from batch {}: {}", batch.index, batch.tokens_minted);
     msg!("Total tokens minted for project: {}", project.tokens_minted);
     msg!("Global total credits issued: {}", registry.total_credits_issued);
 
     Ok(())
 }
 
-// Legacy mint_credits (deprecated)
-pub fn mint_credits(ctx: Context<MintCredits>, amount: u64) -> Result<()> {
-    let project = &mut ctx.accounts.project;
-    
-    // Basic validation
-    require!(
-        project.verification_status == VerificationStatus::Verified,
-        ErrorCode::ProjectNotVerified
-    );
-
-    // Create the context for the `mint_to` instruction
-    let cpi_accounts = MintTo {
-        mint: ctx.accounts.mint.to_account_info(),
-        to: ctx.accounts.recipient_token_account.to_account_info(),
-        authority: ctx.accounts.mint_authority.to_account_info(),
-    };
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-
-    // PDA seeds for signing
-    let project_id_bytes = project.project_id.as_bytes();
-    let seeds = &[
-        b"project",
-        project.owner.as_ref(),
-        project_id_bytes,
-        &[project.bump],
-    ];
-    let signer = &[&seeds[..]];
-
-    // Mint the tokens
-    token_interface::mint_to(
-        CpiContext::new_with_signer(cpi_program, cpi_accounts, signer),
+/// Allocates serials from `info` for an issuance, buffer included, and writes its
+/// IssuanceRecord. The buffer starts its own `permanence_period` at issuance.
+#[allow(clippy::too_many_arguments)]
+fn record_issuance(
+    record: &mut Account<IssuanceRecord>,
+    info: &mut CreditMintInfo,
+    project: Pubkey,
+    issuance_batch: Pubkey,
+    (amount, buffer_amount): (u64, u64),
+    scale: u64,
+    permanence_period: i64,
+    bump: u8,
+) -> Result<()> {
+    let (serial_start, serial_end) = allocate_serials(info, amount + buffer_amount, scale)?;
+    let issued_at = Clock::get()?.unix_timestamp;
+    let permanence_ends_at = issued_at.checked_add(permanence_period).ok_or(ErrorCode::MathOverflow)?;
+    record.set_inner(IssuanceRecord {
+        credit_mint: info.mint,
+        project,
+        issuance_batch,
+        index: info.issuances,
+        serial_prefix: info.serial_prefix.clone(),
+        serial_start,
+        serial_end,
+        serials_retired: 0,
         amount,
-    )?;
-
-    // Update the project's credits_issued count
-    project.credits_issued += amount;
-    project.tokens_minted += amount;
-
-    msg!("Minted {} credits for project {}", amount, project.project_id);
-
+        buffer_amount,
+        buffer_held: buffer_amount,
+        buffer_cancelled: 0,
+        permanence_ends_at,
+        issued_at,
+        bump,
+    });
+    info.issuances += 1;
+
+    msg!("🔢 Serials {}-{:09}..{:09} issued", record.serial_prefix, serial_start, serial_end);
     Ok(())
 }
 
@@ -220,17 +285,36 @@ pub fn batch_mint_credits<'info>(
         project.compliance.audit_status == "Approved",
         ErrorCode::ComplianceNotApproved
     );
+    require!(project.active_dispute.is_none(), ErrorCode::ProjectDisputed);
+    require!(
+        project.verification_current(Clock::get()?.unix_timestamp),
+        ErrorCode::VerificationExpired
+    );
+    // Domestic-only mints take each recipient's ParticipantJurisdiction after its token account
+    let domestic = !ctx.accounts.credit_mint_info.export_authorized;
+    let accounts_per_recipient = if domestic { 2 } else { 1 };
     require!(
-        remaining_accounts.len() == amounts.len(),
+        remaining_accounts.len() == amounts.len() * accounts_per_recipient,
         ErrorCode::InvalidEcosystemType // Reusing error or add mismatch error
     );
 
     let total_batch_amount: u64 = amounts.iter().sum();
-    let verified_capacity = project.carbon_tons_estimated * 10u64.pow(6);
+    let scale = credit_scale(ctx.accounts.carbon_token_mint.decimals)?;
+    let batch = &ctx.accounts.issuance_batch;
+    require!(batch.status == IssuanceBatchStatus::Verified, ErrorCode::BatchNotVerified);
+    let buffer = buffer_contribution(&ctx.accounts.registry_config, project, total_batch_amount, scale)?;
+    let issued = total_batch_amount + buffer;
+    let verified_capacity = batch.verified_tons.checked_mul(scale).ok_or(ErrorCode::MathOverflow)?;
     require!(
-        project.tokens_minted + total_batch_amount <= verified_capacity,
+        batch.tokens_minted + issued <= verified_capacity,
         ErrorCode::ExceedsVerifiedCapacity
     );
+    record_export_issuance(
+        project,
+        &ctx.accounts.credit_mint_info,
+        issued,
+        scale,
+    )?;
 
     // Get the bump from registry without mutable borrow
     let registry_bump = ctx.accounts.registry.bump;
@@ -241,9 +325,24 @@ pub fn batch_mint_credits<'info>(
     let mint_info = ctx.accounts.carbon_token_mint.to_account_info();
     let registry_info = ctx.accounts.registry.to_account_info();
 
-    for (i, recipient_info) in remaining_accounts.iter().enumerate() {
+    if domestic {
+        thaw_if_frozen(
+            &ctx.accounts.buffer_vault.to_account_info(),
+            &mint_info,
+            &registry_info,
+            &cpi_program,
+            registry_bump,
+        )?;
+    }
+
+    for (i, recipient) in remaining_accounts.chunks(accounts_per_recipient).enumerate() {
         let amount = amounts[i];
         if amount == 0 { continue; }
+        let recipient_info = &recipient[0];
+        if domestic {
+            check_batch_recipient(&ctx.accounts.credit_mint_info, recipient_info, &recipient[1])?;
+            thaw_if_frozen(recipient_info, &mint_info, &registry_info, &cpi_program, registry_bump)?;
+        }
 
         let cpi_accounts = MintTo {
             mint: mint_info.clone(),
@@ -257,11 +356,34 @@ pub fn batch_mint_credits<'info>(
         )?;
     }
 
+    deposit_buffer(
+        &mut ctx.accounts.credit_mint_info,
+        MintTo {
+            mint: mint_info,
+            to: ctx.accounts.buffer_vault.to_account_info(),
+            authority: registry_info,
+        },
+        cpi_program,
+        registry_bump,
+        buffer,
+    )?;
+
     // Update tracking
+    record_issuance(
+        &mut ctx.accounts.issuance_record,
+        &mut ctx.accounts.credit_mint_info,
+        project.key(),
+        ctx.accounts.issuance_batch.key(),
+        (total_batch_amount, buffer),
+        scale,
+        ctx.accounts.registry_config.permanence_period,
+        ctx.bumps.issuance_record,
+    )?;
     let registry = &mut ctx.accounts.registry;
-    project.credits_issued += total_batch_amount;
-    project.tokens_minted += total_batch_amount;
-    registry.total_credits_issued += total_batch_amount;
+    ctx.accounts.issuance_batch.tokens_minted += issued;
+    project.credits_issued += issued;
+    project.tokens_minted += issued;
+    registry.total_credits_issued += issued;
 
     msg!("Batch minted {} credits to {} recipients", total_batch_amount, amounts.len());
 
@@ -1,7 +1,24 @@
 pub mod contexts;
 pub mod errors;
+pub mod registry_config;
 pub mod register_project;
+pub mod amend_project;
+pub mod withdraw_project;
+pub mod project_ownership;
+pub mod sector_data;
+pub mod double_counting;
+pub mod lifecycle;
 pub mod verify_project;
+pub mod verification_round;
+pub mod verifier_stake;
+pub mod verifier_lifecycle;
+pub mod disputes;
+pub mod validity;
+pub mod escrow;
+pub mod audit_jobs;
+pub mod issuance_batches;
+pub mod credit_mints;
+pub mod buffer_pool;
 pub mod mint_credits;
 pub mod trade_credits;
 pub mod track_impact;
@@ -11,8 +28,25 @@ pub mod compliance;
 
 pub use contexts::*;
 
+pub use registry_config::*;
 pub use register_project::*;
+pub use amend_project::*;
+pub use withdraw_project::*;
+pub use project_ownership::*;
+pub use sector_data::*;
+pub use double_counting::*;
+pub use lifecycle::*;
 pub use verify_project::*;
+pub use verification_round::*;
+pub use verifier_stake::*;
+pub use verifier_lifecycle::*;
+pub use disputes::*;
+pub use validity::*;
+pub use escrow::*;
+pub use audit_jobs::*;
+pub use issuance_batches::*;
+pub use credit_mints::*;
+pub use buffer_pool::*;
 pub use mint_credits::*;
 pub use trade_credits::*;
 pub use track_impact::*;
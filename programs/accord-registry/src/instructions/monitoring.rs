@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::models::*;
-use crate::instructions::contexts::*;
+use crate::instructions::{contexts::*, errors::ErrorCode, lifecycle::transition_status};
 
 pub fn initialize_platform_stats(ctx: Context<InitializePlatformStats>) -> Result<()> {
     let stats = &mut ctx.accounts.stats;
@@ -13,12 +13,33 @@ pub fn initialize_platform_stats(ctx: Context<InitializePlatformStats>) -> Resul
     Ok(())
 }
 
+/// Range checks for fixed-point monitoring values
+fn validate_monitoring_data(data: &MonitoringDataInput) -> Result<()> {
+    use fixed_point::{MAX_INDEX, MAX_PERCENT, MAX_PH};
+
+    require!(data.ecosystem_health_score <= MAX_PERCENT, ErrorCode::InvalidMeasurement);
+    require!((-MAX_INDEX..=MAX_INDEX).contains(&data.ndvi_index), ErrorCode::InvalidMeasurement);
+    require!(data.water_quality.ph_level <= MAX_PH, ErrorCode::InvalidMeasurement);
+    require!(
+        data.iot_sensor_data.iter().all(|reading| {
+            reading.ph_level <= MAX_PH
+                && reading.humidity <= MAX_PERCENT
+                && reading.soil_moisture <= MAX_PERCENT
+        }),
+        ErrorCode::InvalidMeasurement
+    );
+
+    Ok(())
+}
+
 pub fn submit_monitoring_data(
     ctx: Context<SubmitMonitoringData>,
     _project_id: String,
     _timestamp: i64,
     monitoring_data: MonitoringDataInput,
 ) -> Result<()> {
+    validate_monitoring_data(&monitoring_data)?;
+
     let monitoring = &mut ctx.accounts.monitoring_data;
     let _project = &mut ctx.accounts.project;
 
@@ -33,11 +54,21 @@ pub fn submit_monitoring_data(
     monitoring.ecosystem_health_score = monitoring_data.ecosystem_health_score;
 
     // Dynamic Monitoring Trigger
-    // If health score drops below 50, flag project for review
-    if monitoring.ecosystem_health_score < 50.0 {
+    // If health score drops below the configured threshold, flag verified projects for review
+    if monitoring.ecosystem_health_score < ctx.accounts.registry_config.health_threshold {
         let project = &mut ctx.accounts.project;
-        project.verification_status = VerificationStatus::Monitoring;
-        msg!("WARNING: Project health critical! Status updated to Monitoring.");
+        match project.verification_status {
+            VerificationStatus::Verified => {
+                transition_status(project, VerificationStatus::Monitoring, ctx.accounts.data_provider.key())?;
+                msg!("WARNING: Project health critical! Status updated to Monitoring.");
+            }
+            VerificationStatus::Monitoring => {
+                msg!("WARNING: Project health still critical, already under Monitoring.");
+            }
+            _ => {
+                msg!("WARNING: Project health critical, but project is not verified ({:?}).", project.verification_status);
+            }
+        }
     }
 
     msg!("Monitoring data submitted for project: {}", monitoring.project_id);
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::instructions::{contexts::*, errors::ErrorCode};
+
+/// Event emitted when a project changes hands
+#[event]
+pub struct ProjectOwnershipTransferred {
+    pub project: Pubkey,
+    pub project_id: String,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub transfer_index: u32,
+    pub timestamp: i64,
+}
+
+pub fn propose_project_transfer(
+    ctx: Context<ProposeProjectTransfer>,
+    new_owner: Option<Pubkey>,
+) -> Result<()> {
+    let project = &mut ctx.accounts.project;
+
+    if let Some(new_owner) = new_owner {
+        require_keys_neq!(new_owner, project.owner, ErrorCode::InvalidNewOwner);
+        msg!("🤝 Ownership of {} proposed to {}", project.project_id, new_owner);
+    } else {
+        msg!("🚫 Pending ownership transfer of {} cancelled", project.project_id);
+    }
+    project.pending_owner = new_owner;
+
+    Ok(())
+}
+
+pub fn accept_project_transfer(ctx: Context<AcceptProjectTransfer>) -> Result<()> {
+    let project = &mut ctx.accounts.project;
+    let record = &mut ctx.accounts.ownership_record;
+    let new_owner = ctx.accounts.new_owner.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    record.project = project.key();
+    record.transfer_index = project.ownership_transfers;
+    record.previous_owner = project.owner;
+    record.new_owner = new_owner;
+    record.accepted_at = now;
+    record.bump = ctx.bumps.ownership_record;
+
+    project.owner = new_owner;
+    project.pending_owner = None;
+    project.ownership_transfers += 1;
+
+    msg!("✅ Project {} transferred: {} -> {}", project.project_id, record.previous_owner, new_owner);
+    emit!(ProjectOwnershipTransferred {
+        project: project.key(),
+        project_id: project.project_id.clone(),
+        previous_owner: record.previous_owner,
+        new_owner,
+        transfer_index: record.transfer_index,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -3,23 +3,90 @@ use anchor_lang::system_program;
 use crate::models::*;
 use crate::instructions::contexts::*;
 use crate::instructions::errors::ErrorCode;
-use h3o::{LatLng, Resolution};
+use crate::instructions::double_counting::reserve_cells;
+use crate::instructions::lifecycle::transition_status;
+use crate::instructions::escrow::start_audit_deadline;
+use crate::auth_utils::validation::{compliance_id_message, verify_ed25519_instruction};
+use h3o::{geom::{Polygon, ToCells}, CellIndex, LatLng, Resolution};
+
+/// Upper bound on the number of H3 cells a single project boundary may cover
+pub const MAX_PROJECT_CELLS: usize = 256;
+
+/// H3 cell containing the project centroid; used as the project's double-counting ID.
+pub fn centroid_cell(location: &GeoLocation, resolution: Resolution) -> Result<CellIndex> {
+    let centroid = LatLng::new(
+        fixed_point::to_degrees(location.latitude),
+        fixed_point::to_degrees(location.longitude),
+    )
+    .map_err(|_| ErrorCode::InvalidCoordinates)?;
+    Ok(centroid.to_cell(resolution))
+}
+
+/// Converts a project location into the sorted, de-duplicated set of H3 cells it
+/// covers at `resolution`.
+///
+/// The centroid cell is always included. When `polygon_coordinates` describes a
+/// boundary (at least 3 `[latitude, longitude]` vertices), every cell whose
+/// center falls inside it is added as well.
+pub fn project_h3_cells(location: &GeoLocation, resolution: u8) -> Result<Vec<u64>> {
+    let resolution = Resolution::try_from(resolution).map_err(|_| ErrorCode::InvalidConfigValue)?;
+    require!(
+        location.polygon_coordinates.len() <= Project::MAX_POLYGON_VERTICES,
+        ErrorCode::ProjectBoundaryTooLarge
+    );
+    require!(location.has_valid_coordinates(), ErrorCode::InvalidCoordinates);
+
+    let mut cells: Vec<u64> = vec![centroid_cell(location, resolution)?.into()];
+
+    if location.polygon_coordinates.len() >= 3 {
+        let ring: Vec<geo_types::Coord> = location
+            .polygon_coordinates
+            .iter()
+            .map(|[lat, lng]| geo_types::Coord {
+                x: fixed_point::to_degrees(*lng),
+                y: fixed_point::to_degrees(*lat),
+            })
+            .collect();
+
+        let boundary = geo_types::Polygon::new(geo_types::LineString::new(ring), Vec::new());
+        let polygon = Polygon::from_degrees(boundary).map_err(|_| ErrorCode::InvalidCoordinates)?;
+
+        // Bounded so an oversized boundary fails instead of exhausting compute
+        for cell in polygon.to_cells(resolution).take(MAX_PROJECT_CELLS + 1) {
+            cells.push(cell.into());
+        }
+    }
+
+    cells.sort_unstable();
+    cells.dedup();
+    require!(cells.len() <= MAX_PROJECT_CELLS, ErrorCode::ProjectBoundaryTooLarge);
+
+    Ok(cells)
+}
 
-/// Minimum verification fee in lamports (0.1 SOL)
-pub const MINIMUM_VERIFICATION_FEE: u64 = 100_000_000;
+/// Formats H3 cells as their canonical hex strings for logging.
+pub fn format_h3_cells(cells: &[u64]) -> Vec<String> {
+    cells
+        .iter()
+        .filter_map(|cell| CellIndex::try_from(*cell).ok())
+        .map(|cell| cell.to_string())
+        .collect()
+}
 
 pub fn initialize_double_counting_registry(
     ctx: Context<InitializeDoubleCountingRegistry>,
 ) -> Result<()> {
     let registry = &mut ctx.accounts.double_counting_registry;
     registry.authority = ctx.accounts.authority.key();
+    registry.total_shards = 0;
+    registry.total_cells = 0;
     registry.bump = ctx.bumps.double_counting_registry;
     msg!("Double Counting Registry Initialized");
     Ok(())
 }
 
-pub fn register_project(
-    ctx: Context<RegisterProject>,
+pub fn register_project<'info>(
+    ctx: Context<'_, '_, '_, 'info, RegisterProject<'info>>,
     project_data: ProjectRegistrationData,
     verification_fee: u64,
 ) -> Result<()> {
@@ -47,26 +114,48 @@ pub fn register_project(
     // VALIDATION 3: Minimum Verification Fee Required
     // ========================================
     require!(
-        verification_fee >= MINIMUM_VERIFICATION_FEE,
+        verification_fee >= ctx.accounts.registry_config.min_verification_fee,
         ErrorCode::InsufficientVerificationFee
     );
 
     // ========================================
-    // H3 Geospatial Double-Counting Check
+    // VALIDATION 4: Government Signature over the Registry ID
+    // The preceding instruction must be an ed25519 verification proving the
+    // government authority signed (ccts_registry_id, owner, location)
     // ========================================
-    let lat = project_data.location.latitude;
-    let lng = project_data.location.longitude;
-    
-    let coord = LatLng::new(lat, lng).map_err(|_| ErrorCode::InvalidCoordinates)?;
-    let cell_index = coord.to_cell(Resolution::Eight);
-    let cell_u64: u64 = cell_index.into();
-
-    if double_counting_registry.registered_locations.contains(&cell_u64) {
-        return Err(ErrorCode::ProjectAlreadyProcessed.into());
-    }
+    let attestation = compliance_id_message(
+        &project_data.ccts_registry_id,
+        &ctx.accounts.project_owner.key(),
+        &project_data.location,
+    )?;
+    verify_ed25519_instruction(
+        &ctx.accounts.instructions_sysvar.to_account_info(),
+        &registry.government_authority,
+        &attestation,
+        &project_data.compliance_id_signature,
+    )?;
 
-    // Register Location
-    double_counting_registry.registered_locations.push(cell_u64);
+    // ========================================
+    // H3 Geospatial Double-Counting Check
+    // Fill the full project boundary, not just the centroid
+    // ========================================
+    let h3_resolution = ctx.accounts.registry_config.h3_resolution;
+    let project_cells = project_h3_cells(&project_data.location, h3_resolution)?;
+    let cell_index = centroid_cell(
+        &project_data.location,
+        Resolution::try_from(h3_resolution).map_err(|_| ErrorCode::InvalidConfigValue)?,
+    )?;
+
+
+    // Reserve cells in their shards (created on demand, paid by the developer)
+    reserve_cells(
+        ctx.program_id,
+        double_counting_registry,
+        ctx.remaining_accounts,
+        &ctx.accounts.project_owner.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &project_cells,
+    )?;
 
     // ========================================
     // COLLECT VERIFICATION ESCROW
@@ -85,11 +174,35 @@ pub fn register_project(
     // SET PROJECT DATA
     // ========================================
     project_account.project_id = project_data.project_id.clone();
+    // A withdrawn ID's child accounts stay behind; a new generation keeps their seeds apart
+    let registry_id_record = &mut ctx.accounts.registry_id_record;
+    project_account.generation = registry_id_record.registrations;
+    registry_id_record.registrations = registry_id_record
+        .registrations
+        .checked_add(1)
+        .ok_or(ErrorCode::MathOverflow)?;
+    registry_id_record.bump = ctx.bumps.registry_id_record;
     project_account.owner = ctx.accounts.project_owner.key();
+    project_account.pending_owner = None;
+    project_account.ownership_transfers = 0;
+    project_account.verification_rounds = 0;
+    project_account.min_verifier_stake = 0;
+    project_account.challenge_ends_at = 0;
+    project_account.active_dispute = None;
+    project_account.disputes = 0;
+    project_account.verification_records = 0;
+    project_account.verification_expires_at = 0;
+    project_account.audit_jobs = 0;
+    project_account.issuance_batches = 0;
+    project_account.monitored_until = 0;
+    project_account.risk_buffer_bps = None;
+    project_account.export_tokens_issued = 0;
     project_account.ipfs_cid = project_data.ipfs_cid;
+    project_account.version = 1;
     project_account.carbon_tons_estimated = project_data.carbon_tons_estimated;
     project_account.project_sector = project_data.project_sector;
     project_account.location = project_data.location;
+    project_account.h3_resolution = h3_resolution;
     project_account.area_hectares = project_data.area_hectares;
     project_account.establishment_date = project_data.establishment_date;
     project_account.vintage_year = project_data.vintage_year;
@@ -97,13 +210,15 @@ pub fn register_project(
     project_account.available_quantity = project_data.carbon_tons_estimated;
 
     // Status is now AwaitingAudit since escrow is collected
-    project_account.verification_status = VerificationStatus::AwaitingAudit;
+    transition_status(project_account, VerificationStatus::AwaitingAudit, ctx.accounts.project_owner.key())?;
     project_account.credits_issued = 0;
     project_account.tokens_minted = 0;
     project_account.bump = ctx.bumps.project;
     project_account.verifier = None;
     project_account.verification_fee_lamports = verification_fee;
     project_account.audit_escrow_balance = verification_fee; // Escrow is now funded
+    project_account.escrow_mint = None;
+    start_audit_deadline(project_account, &ctx.accounts.registry_config)?;
 
     // Compliance State - ICM Registry ID is now verified as project_id
     project_account.compliance = ComplianceState {
@@ -125,9 +240,8 @@ pub fn register_project(
     registry.total_projects += 1;
 
     msg!("✅ Project registered with ICM Registry ID: {}", project_account.project_id);
-    msg!("📍 H3 Cell: {}", cell_index.to_string());
+    msg!("📍 H3 Cell: {} ({} cells reserved)", cell_index, project_cells.len());
     msg!("💰 Escrow Funded: {} lamports", verification_fee);
-    msg!("📊 Status: AwaitingAudit");
 
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,202 @@
+use anchor_lang::prelude::*;
+use crate::models::*;
+use crate::instructions::{contexts::*, errors::ErrorCode};
+use crate::instructions::double_counting::SHARD_RESOLUTION_OFFSET;
+
+/// Default minimum verification fee in lamports (0.1 SOL)
+pub const DEFAULT_MIN_VERIFICATION_FEE: u64 = 100_000_000;
+
+/// Default H3 resolution for double-counting cells (~0.74 km² per cell)
+pub const DEFAULT_H3_RESOLUTION: u8 = 8;
+
+/// Default minimum number of attestations in a verification round
+pub const DEFAULT_MIN_VERIFICATION_QUORUM: u8 = 2;
+
+/// Default delay before unbonded verifier stake can be withdrawn (7 days)
+pub const DEFAULT_STAKE_UNBONDING_PERIOD: i64 = 7 * 24 * 60 * 60;
+
+/// Longest unbonding delay governance may set (1 year)
+pub const MAX_STAKE_UNBONDING_PERIOD: i64 = 365 * 24 * 60 * 60;
+
+/// Default window after verification during which it can be disputed (7 days)
+pub const DEFAULT_CHALLENGE_PERIOD: i64 = 7 * 24 * 60 * 60;
+
+/// Longest challenge window governance may set (90 days)
+pub const MAX_CHALLENGE_PERIOD: i64 = 90 * 24 * 60 * 60;
+
+/// Default bond a challenger posts to dispute a verification (0.5 SOL)
+pub const DEFAULT_MIN_DISPUTE_BOND: u64 = 500_000_000;
+
+/// Default wait before a verifier may audit the same owner's projects again (90 days)
+pub const DEFAULT_AUDIT_COOLDOWN: i64 = 90 * 24 * 60 * 60;
+
+/// Longest audit cooldown governance may set (5 years)
+pub const MAX_AUDIT_COOLDOWN: i64 = 5 * 365 * 24 * 60 * 60;
+
+/// Default period a verification stays valid before renewal (5 years)
+pub const DEFAULT_VERIFICATION_VALIDITY_PERIOD: i64 = 5 * 365 * 24 * 60 * 60;
+
+/// Longest verification validity governance may set (30 years)
+pub const MAX_VERIFICATION_VALIDITY_PERIOD: i64 = 30 * 365 * 24 * 60 * 60;
+
+/// Default time a funded audit escrow waits for a verifier before the owner may reclaim it (60 days)
+pub const DEFAULT_AUDIT_TIMEOUT: i64 = 60 * 24 * 60 * 60;
+
+/// Longest audit timeout governance may set (1 year)
+pub const MAX_AUDIT_TIMEOUT: i64 = 365 * 24 * 60 * 60;
+
+/// Default minimum stablecoin escrow (100 units of a 6-decimal stablecoin)
+pub const DEFAULT_MIN_STABLE_VERIFICATION_FEE: u64 = 100_000_000;
+
+/// Default share of BlueCarbon/Forestry issuance withheld into the buffer pool (20%)
+pub const DEFAULT_NATURE_BUFFER_BPS: u16 = 2_000;
+
+/// Largest buffer rate governance or a risk assessment may set (50%)
+pub const MAX_BUFFER_BPS: u16 = 5_000;
+
+/// Default time after first issuance before buffer credits are released (40 years)
+pub const DEFAULT_PERMANENCE_PERIOD: i64 = 40 * 365 * 24 * 60 * 60;
+
+/// Longest permanence period governance may set (100 years)
+pub const MAX_PERMANENCE_PERIOD: i64 = 100 * 365 * 24 * 60 * 60;
+
+/// Default stake every verification round attester must hold (1 SOL)
+pub const DEFAULT_MIN_VERIFIER_STAKE: u64 = 1_000_000_000;
+
+/// Default ecosystem health score below which verified projects go to Monitoring (50.000)
+pub const DEFAULT_HEALTH_THRESHOLD: MilliUnits = 50 * fixed_point::MILLI_PER_UNIT;
+
+pub fn initialize_registry_config(ctx: Context<InitializeRegistryConfig>) -> Result<()> {
+    let config = &mut ctx.accounts.registry_config;
+
+    config.min_verification_fee = DEFAULT_MIN_VERIFICATION_FEE;
+    config.h3_resolution = DEFAULT_H3_RESOLUTION;
+    config.credit_decimals = ctx.accounts.carbon_token_mint.decimals;
+    config.health_threshold = DEFAULT_HEALTH_THRESHOLD;
+    config.min_verification_quorum = DEFAULT_MIN_VERIFICATION_QUORUM;
+    config.stake_unbonding_period = DEFAULT_STAKE_UNBONDING_PERIOD;
+    config.challenge_period = DEFAULT_CHALLENGE_PERIOD;
+    config.min_dispute_bond = DEFAULT_MIN_DISPUTE_BOND;
+    config.audit_cooldown = DEFAULT_AUDIT_COOLDOWN;
+    config.verification_validity_period = DEFAULT_VERIFICATION_VALIDITY_PERIOD;
+    config.audit_timeout = DEFAULT_AUDIT_TIMEOUT;
+    config.stable_escrow_mint = None;
+    config.min_stable_verification_fee = DEFAULT_MIN_STABLE_VERIFICATION_FEE;
+    config.nature_buffer_bps = DEFAULT_NATURE_BUFFER_BPS;
+    config.permanence_period = DEFAULT_PERMANENCE_PERIOD;
+    config.min_verifier_stake = DEFAULT_MIN_VERIFIER_STAKE;
+    config.last_proposal_id = None;
+    config.updated_at = Clock::get()?.unix_timestamp;
+    config.bump = ctx.bumps.registry_config;
+
+    msg!("⚙️ Registry config initialized: {:?}", config);
+    Ok(())
+}
+
+/// Validates and applies an executed `UpdateRegistry` proposal to the config.
+///
+/// Cells reserved at different resolutions live in different shards and are
+/// never checked against each other, so `h3_resolution` can only change while
+/// `double_counting_registry` shows no reserved cells.
+pub fn apply_config_update(
+    config: &mut RegistryConfig,
+    double_counting_registry: Option<&DoubleCountingRegistry>,
+    update: RegistryConfigUpdate,
+    proposal_id: u64,
+) -> Result<()> {
+    if let Some(min_verification_fee) = update.min_verification_fee {
+        require!(min_verification_fee > 0, ErrorCode::InvalidConfigValue);
+        config.min_verification_fee = min_verification_fee;
+    }
+    if let Some(h3_resolution) = update.h3_resolution {
+        require!(
+            (SHARD_RESOLUTION_OFFSET..=15).contains(&h3_resolution),
+            ErrorCode::InvalidConfigValue
+        );
+        if h3_resolution != config.h3_resolution {
+            let reserved_cells = double_counting_registry
+                .map(|registry| registry.total_cells)
+                .ok_or(ErrorCode::H3ResolutionLocked)?;
+            require!(reserved_cells == 0, ErrorCode::H3ResolutionLocked);
+        }
+        config.h3_resolution = h3_resolution;
+    }
+    if let Some(health_threshold) = update.health_threshold {
+        require!(health_threshold <= fixed_point::MAX_PERCENT, ErrorCode::InvalidConfigValue);
+        config.health_threshold = health_threshold;
+    }
+    if let Some(min_verification_quorum) = update.min_verification_quorum {
+        require!(
+            (1..=VerificationRound::MAX_ATTESTATIONS as u8).contains(&min_verification_quorum),
+            ErrorCode::InvalidConfigValue
+        );
+        config.min_verification_quorum = min_verification_quorum;
+    }
+    if let Some(stake_unbonding_period) = update.stake_unbonding_period {
+        require!(
+            (1..=MAX_STAKE_UNBONDING_PERIOD).contains(&stake_unbonding_period),
+            ErrorCode::InvalidConfigValue
+        );
+        config.stake_unbonding_period = stake_unbonding_period;
+    }
+    if let Some(challenge_period) = update.challenge_period {
+        require!(
+            (0..=MAX_CHALLENGE_PERIOD).contains(&challenge_period),
+            ErrorCode::InvalidConfigValue
+        );
+        config.challenge_period = challenge_period;
+    }
+    if let Some(min_dispute_bond) = update.min_dispute_bond {
+        require!(min_dispute_bond > 0, ErrorCode::InvalidConfigValue);
+        config.min_dispute_bond = min_dispute_bond;
+    }
+    if let Some(audit_cooldown) = update.audit_cooldown {
+        require!(
+            (0..=MAX_AUDIT_COOLDOWN).contains(&audit_cooldown),
+            ErrorCode::InvalidConfigValue
+        );
+        config.audit_cooldown = audit_cooldown;
+    }
+    if let Some(verification_validity_period) = update.verification_validity_period {
+        require!(
+            (1..=MAX_VERIFICATION_VALIDITY_PERIOD).contains(&verification_validity_period),
+            ErrorCode::InvalidConfigValue
+        );
+        config.verification_validity_period = verification_validity_period;
+    }
+    if let Some(audit_timeout) = update.audit_timeout {
+        require!(
+            (1..=MAX_AUDIT_TIMEOUT).contains(&audit_timeout),
+            ErrorCode::InvalidConfigValue
+        );
+        config.audit_timeout = audit_timeout;
+    }
+    if let Some(stable_escrow_mint) = update.stable_escrow_mint {
+        config.stable_escrow_mint = Some(stable_escrow_mint);
+    }
+    if let Some(min_stable_verification_fee) = update.min_stable_verification_fee {
+        require!(min_stable_verification_fee > 0, ErrorCode::InvalidConfigValue);
+        config.min_stable_verification_fee = min_stable_verification_fee;
+    }
+    if let Some(nature_buffer_bps) = update.nature_buffer_bps {
+        require!(nature_buffer_bps <= MAX_BUFFER_BPS, ErrorCode::InvalidConfigValue);
+        config.nature_buffer_bps = nature_buffer_bps;
+    }
+    if let Some(permanence_period) = update.permanence_period {
+        require!(
+            (1..=MAX_PERMANENCE_PERIOD).contains(&permanence_period),
+            ErrorCode::InvalidConfigValue
+        );
+        config.permanence_period = permanence_period;
+    }
+    if let Some(min_verifier_stake) = update.min_verifier_stake {
+        require!(min_verifier_stake > 0, ErrorCode::InvalidConfigValue);
+        config.min_verifier_stake = min_verifier_stake;
+    }
+
+    config.last_proposal_id = Some(proposal_id);
+    config.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("⚙️ Registry config updated by proposal {}: {:?}", proposal_id, config);
+    Ok(())
+}
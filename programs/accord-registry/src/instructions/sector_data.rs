@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use crate::models::*;
+use crate::instructions::{contexts::*, errors::ErrorCode};
+use crate::instructions::lifecycle::transition_status;
+
+/// Upper bound for a single biomass or soil carbon pool (2,000 tC/ha)
+pub const MAX_CARBON_POOL: MilliUnits = 2_000 * fixed_point::MILLI_PER_UNIT;
+
+/// Upper bound for a grid emission factor (2 tCO2e/MWh)
+pub const MAX_GRID_EMISSION_FACTOR: MilliUnits = 2 * fixed_point::MILLI_PER_UNIT;
+
+/// Checks that `details` belongs to `sector` and that every value is in range.
+pub fn validate_sector_details(sector: &ProjectSector, details: &SectorDetails) -> Result<()> {
+    match (sector, details) {
+        (ProjectSector::BlueCarbon | ProjectSector::Forestry, SectorDetails::NatureBased(nature)) => {
+            require!(
+                !nature.species_composition.is_empty()
+                    && nature.species_composition.len() <= ProjectSectorData::MAX_SPECIES,
+                ErrorCode::InvalidSectorData
+            );
+            require!(
+                nature
+                    .species_composition
+                    .iter()
+                    .all(|species| !species.is_empty() && species.len() <= ProjectSectorData::SPECIES_NAME_LEN),
+                ErrorCode::InvalidSectorData
+            );
+
+            let pools = [
+                nature.above_ground_biomass,
+                nature.below_ground_biomass,
+                nature.soil_carbon_0_30cm,
+                nature.soil_carbon_30_100cm,
+            ];
+            require!(pools.iter().all(|pool| *pool <= MAX_CARBON_POOL), ErrorCode::InvalidSectorData);
+            require!(pools.iter().any(|pool| *pool > 0), ErrorCode::InvalidSectorData);
+            require!(nature.permanence_guarantee_years > 0, ErrorCode::InvalidSectorData);
+        }
+        (ProjectSector::RenewableEnergy, SectorDetails::RenewableEnergy(energy)) => {
+            require!(energy.installed_capacity_mw > 0, ErrorCode::InvalidSectorData);
+            require!(
+                (1..=MAX_GRID_EMISSION_FACTOR).contains(&energy.grid_emission_factor),
+                ErrorCode::InvalidSectorData
+            );
+        }
+        (ProjectSector::WasteManagement, SectorDetails::WasteManagement(waste)) => {
+            require!(waste.tonnes_diverted > 0, ErrorCode::InvalidSectorData);
+        }
+        _ => return Err(ErrorCode::SectorDataMismatch.into()),
+    }
+
+    Ok(())
+}
+
+pub fn initialize_sector_data(
+    ctx: Context<InitializeSectorData>,
+    details: SectorDetails,
+) -> Result<()> {
+    let project = &ctx.accounts.project;
+    validate_sector_details(&project.project_sector, &details)?;
+
+    let sector_data = &mut ctx.accounts.sector_data;
+    sector_data.project = project.key();
+    sector_data.sector = project.project_sector.clone();
+    sector_data.details = details;
+    sector_data.updated_by = ctx.accounts.owner.key();
+    sector_data.updated_at = Clock::get()?.unix_timestamp;
+    sector_data.bump = ctx.bumps.sector_data;
+
+    msg!("🌿 Sector data attached to {} ({:?})", project.project_id, sector_data.sector);
+    Ok(())
+}
+
+pub fn update_sector_data(
+    ctx: Context<UpdateSectorData>,
+    details: SectorDetails,
+) -> Result<()> {
+    let project = &mut ctx.accounts.project;
+    let owner = ctx.accounts.owner.key();
+    validate_sector_details(&project.project_sector, &details)?;
+
+    let sector_data = &mut ctx.accounts.sector_data;
+    sector_data.details = details;
+    sector_data.updated_by = owner;
+    sector_data.updated_at = Clock::get()?.unix_timestamp;
+
+    // Verified results were based on the previous measurements
+    if matches!(
+        project.verification_status,
+        VerificationStatus::Verified | VerificationStatus::Monitoring
+    ) {
+        transition_status(project, VerificationStatus::UnderReview, owner)?;
+    }
+
+    msg!("🌿 Sector data updated for {}", project.project_id);
+    Ok(())
+}
@@ -22,9 +22,9 @@ pub fn generate_impact_report(
     report.reporting_period_end = report_data.reporting_period_end;
     report.carbon_sequestered = report_data.carbon_sequestered;
     report.ecosystem_health_improvement = report_data.ecosystem_health_improvement; // Now taking from report data directly
-    report.biodiversity_increase = 0.0; // Placeholder until we have baseline data storage
-        // (project.species_count_current as f64 - project.species_count_baseline as f64) /
-        // project.species_count_baseline as f64 * 100.0;
+    report.biodiversity_increase = 0; // Placeholder until we have baseline data storage
+        // (project.species_count_current - project.species_count_baseline) * 100_000 /
+        // project.species_count_baseline (milli-percent)
     report.community_benefits = report_data.community_benefits;
     report.economic_impact = report_data.economic_impact;
     report.sdg_contributions = report_data.sdg_contributions;
@@ -34,8 +34,8 @@ pub fn generate_impact_report(
     // project.species_count_current = report_data.species_count_current;
 
     msg!("Impact report generated for project: {}", report.project_id);
-    msg!("Carbon sequestered: {} tons", report.carbon_sequestered);
-    msg!("Ecosystem health improvement: {}%", report.ecosystem_health_improvement);
+    msg!("Carbon sequestered: {} kg", report.carbon_sequestered);
+    msg!("Ecosystem health improvement: {} milli-%", report.ecosystem_health_improvement);
 
     Ok(())
 }
@@ -43,8 +43,8 @@ pub fn generate_impact_report(
 // Legacy ImpactData structure for backwards compatibility
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct ImpactData {
-    pub co2_absorbed: f64,
-    pub biodiversity_index: f64,
+    pub co2_absorbed: MilliUnits,
+    pub biodiversity_index: MilliUnits,
 }
 
 // TrackImpact context (simple version)
@@ -4,7 +4,12 @@ use anchor_lang::solana_program::{
     program::{invoke, invoke_signed},
     system_instruction,
 };
-use crate::instructions::{contexts::*, errors::ErrorCode};
+use crate::models::*;
+use crate::instructions::{
+    contexts::*,
+    credit_mints::{check_credit_recipient, credit_scale, retire_serials, thaw_if_frozen},
+    errors::ErrorCode,
+};
 
 // Use re-exported spl_token_2022 to match dependency versions
 use anchor_spl::token_2022::spl_token_2022::{
@@ -20,6 +25,17 @@ pub fn trade_credits(_ctx: Context<TradeCredits>, amount: u64) -> Result<()> {
 }
 
 pub fn transfer_credits(ctx: Context<TransferCredits>, amount: u64) -> Result<()> {
+    check_credit_recipient(&ctx.accounts.credit_mint_info, ctx.accounts.recipient_jurisdiction.as_ref())?;
+    if !ctx.accounts.credit_mint_info.export_authorized {
+        thaw_if_frozen(
+            &ctx.accounts.to_account.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.registry.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.registry.bump,
+        )?;
+    }
+
     let cpi_accounts = TransferChecked {
         from: ctx.accounts.from_account.to_account_info(),
         mint: ctx.accounts.mint.to_account_info(),
@@ -41,6 +57,11 @@ pub fn retire_credits(
     amount: u64, 
     retirement_id: String
 ) -> Result<()> {
+    require!(
+        !retirement_id.is_empty() && retirement_id.len() <= RetirementRecord::MAX_RETIREMENT_ID_LEN,
+        ErrorCode::InvalidRetirementId
+    );
+
     // 1. Burn Carbon Credits
     let cpi_accounts_burn = Burn {
         mint: ctx.accounts.credit_mint.to_account_info(),
@@ -54,6 +75,25 @@ pub fn retire_credits(
 
     msg!("Burned {} carbon credits.", amount);
 
+    // Cancel the next delivered serials of the chosen issuance
+    let scale = credit_scale(ctx.accounts.credit_mint.decimals)?;
+    let info = &mut ctx.accounts.credit_mint_info;
+    let (serial_start, serial_end) = retire_serials(info, &mut ctx.accounts.issuance_record, amount, scale)?;
+    ctx.accounts.retirement_record.set_inner(RetirementRecord {
+        owner: ctx.accounts.owner.key(),
+        credit_mint: info.mint,
+        project: info.project,
+        certificate_mint: ctx.accounts.certificate_mint.key(),
+        retirement_id: retirement_id.clone(),
+        serial_prefix: info.serial_prefix.clone(),
+        serial_start,
+        serial_end,
+        amount,
+        retired_at: Clock::get()?.unix_timestamp,
+        bump: ctx.bumps.retirement_record,
+    });
+    msg!("🔢 Serials {}-{:09}..{:09} cancelled", info.serial_prefix, serial_start, serial_end);
+
     // 2. Initialize Retirement Certificate Mint (Non-Transferable)
     let space = ExtensionType::try_calculate_account_len::<anchor_spl::token_2022::spl_token_2022::state::Mint>(&[
         ExtensionType::NonTransferable,
@@ -47,7 +47,7 @@ pub struct ExecuteTransferHook<'info> {
     
     /// CHECK: Extra Account - Project Account
     #[account(
-        seeds = [b"project", project.owner.as_ref(), project.project_id.as_bytes()],
+        seeds = [b"project", project.project_id.as_bytes()],
         bump = project.bump,
     )]
     pub project: Account<'info, Project>,
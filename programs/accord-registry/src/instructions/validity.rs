@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::models::*;
+use crate::instructions::{
+    contexts::*,
+    errors::ErrorCode,
+    lifecycle::transition_status,
+    verify_project::initialize_verification,
+};
+
+/// Records how long a fresh verification stays valid. Called whenever a
+/// project becomes Verified.
+pub fn start_validity_period(project: &mut Project, config: &RegistryConfig) -> Result<()> {
+    project.verification_expires_at = Clock::get()?
+        .unix_timestamp
+        .checked_add(config.verification_validity_period)
+        .ok_or(ErrorCode::MathOverflow)?;
+    msg!("📅 Verification valid until {}", project.verification_expires_at);
+    Ok(())
+}
+
+/// Moves a project whose verification has lapsed to Expired. Permissionless.
+pub fn expire_verification(ctx: Context<ExpireVerification>) -> Result<()> {
+    let project = &mut ctx.accounts.project;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        project.verification_expires_at != 0 && !project.verification_current(now),
+        ErrorCode::VerificationNotExpired
+    );
+    transition_status(project, VerificationStatus::Expired, ctx.accounts.cranker.key())?;
+
+    msg!("⌛ Project {} verification expired at {}", project.project_id, project.verification_expires_at);
+    Ok(())
+}
+
+/// Starts re-verification of an Expired project by funding a fresh audit
+/// escrow, exactly as `initialize_verification` does for a first audit.
+pub fn renew_verification(ctx: Context<InitializeVerification>, fee_lamports: u64) -> Result<()> {
+    let project = &ctx.accounts.project;
+    require!(
+        project.verification_status == VerificationStatus::Expired,
+        ErrorCode::VerificationNotExpired
+    );
+    require!(project.verification_fee_lamports == 0, ErrorCode::UnreleasedVerificationFee);
+
+    msg!("🔄 Renewing verification for project {}", project.project_id);
+    initialize_verification(ctx, fee_lamports)
+}
@@ -0,0 +1,192 @@
+use anchor_lang::prelude::*;
+use crate::models::*;
+use crate::instructions::{
+    contexts::*,
+    disputes::start_challenge_window,
+    errors::ErrorCode,
+    lifecycle::transition_status,
+    validity::start_validity_period,
+};
+
+/// Upper bound for `max_deviation_bps` (100% of the median)
+pub const MAX_DEVIATION_BPS: u16 = 10_000;
+
+/// Median of `values`; the mean of the two middle values for even lengths.
+pub fn median(values: &mut [u64]) -> u64 {
+    values.sort_unstable();
+    let mid = values.len() / 2;
+    if values.len() % 2 == 1 {
+        values[mid]
+    } else {
+        ((values[mid - 1] as u128 + values[mid] as u128) / 2) as u64
+    }
+}
+
+/// True if `value` is more than `max_deviation_bps` away from `median`.
+pub fn is_outlier(value: u64, median: u64, max_deviation_bps: u16) -> bool {
+    let deviation = value.abs_diff(median) as u128;
+    deviation * 10_000 > median as u128 * max_deviation_bps as u128
+}
+
+pub fn open_verification_round(
+    ctx: Context<OpenVerificationRound>,
+    quorum: u8,
+    required_verifier_types: Vec<VerifierType>,
+    max_deviation_bps: u16,
+    assigned_verifiers: Vec<Pubkey>,
+) -> Result<()> {
+    let project = &mut ctx.accounts.project;
+    let round = &mut ctx.accounts.verification_round;
+
+    require!(
+        matches!(
+            project.verification_status,
+            VerificationStatus::AwaitingAudit | VerificationStatus::UnderReview | VerificationStatus::Monitoring
+        ),
+        ErrorCode::InvalidStatusTransition
+    );
+    require!(
+        quorum >= ctx.accounts.registry_config.min_verification_quorum
+            && quorum as usize <= VerificationRound::MAX_ATTESTATIONS,
+        ErrorCode::InvalidVerificationRound
+    );
+    require!(
+        required_verifier_types.len() <= VerificationRound::MAX_REQUIRED_TYPES
+            && required_verifier_types.len() <= quorum as usize,
+        ErrorCode::InvalidVerificationRound
+    );
+    require!(
+        required_verifier_types
+            .iter()
+            .enumerate()
+            .all(|(i, verifier_type)| !required_verifier_types[..i].contains(verifier_type)),
+        ErrorCode::InvalidVerificationRound
+    );
+    require!(
+        (1..=MAX_DEVIATION_BPS).contains(&max_deviation_bps),
+        ErrorCode::InvalidVerificationRound
+    );
+    // An assigned panel must be able to reach quorum on its own
+    require!(
+        assigned_verifiers.is_empty()
+            || (assigned_verifiers.len() >= quorum as usize
+                && assigned_verifiers.len() <= VerificationRound::MAX_ATTESTATIONS),
+        ErrorCode::InvalidVerificationRound
+    );
+    require!(
+        assigned_verifiers
+            .iter()
+            .enumerate()
+            .all(|(i, verifier)| *verifier != project.owner && !assigned_verifiers[..i].contains(verifier)),
+        ErrorCode::InvalidVerificationRound
+    );
+
+    round.project = project.key();
+    round.round = project.verification_rounds;
+    round.opened_by = ctx.accounts.admin.key();
+    round.quorum = quorum;
+    round.required_verifier_types = required_verifier_types;
+    round.max_deviation_bps = max_deviation_bps;
+    round.assigned_verifiers = assigned_verifiers;
+    round.attestations = Vec::new();
+    round.finalized = false;
+    round.verified_carbon_tons = 0;
+    round.quality_rating = 0;
+    round.fee_pool = 0;
+    round.fee_paid = 0;
+    round.opened_at = Clock::get()?.unix_timestamp;
+    round.finalized_at = 0;
+    round.bump = ctx.bumps.verification_round;
+
+    project.verification_rounds += 1;
+
+    msg!("🗳️ Verification round {} opened for {}", round.round, project.project_id);
+    msg!("Quorum: {}, required types: {:?}", round.quorum, round.required_verifier_types);
+
+    Ok(())
+}
+
+/// Aggregates the round's attestations and verifies the project once quorum is met.
+///
+/// Tonnage more than `max_deviation_bps` from the median of all attestations is
+/// marked as an outlier. The remaining inliers must reach the quorum and include
+/// every required verifier type; the verified tonnage and quality rating are
+/// their medians.
+pub fn finalize_verification_round(ctx: Context<FinalizeVerificationRound>) -> Result<()> {
+    let project = &mut ctx.accounts.project;
+    let round = &mut ctx.accounts.verification_round;
+
+    require!(!round.finalized, ErrorCode::VerificationRoundFinalized);
+    require!(round.round + 1 == project.verification_rounds, ErrorCode::VerificationRoundFinalized);
+
+    let mut all_tons: Vec<u64> = round.attestations.iter().map(|a| a.carbon_tons).collect();
+    require!(all_tons.len() >= round.quorum as usize, ErrorCode::QuorumNotReached);
+    let overall_median = median(&mut all_tons);
+
+    let max_deviation_bps = round.max_deviation_bps;
+    for attestation in round.attestations.iter_mut() {
+        attestation.is_outlier = is_outlier(attestation.carbon_tons, overall_median, max_deviation_bps);
+        if attestation.is_outlier {
+            msg!("⚠️ Outlier attestation by {}: {} tons (median {})", attestation.verifier, attestation.carbon_tons, overall_median);
+        }
+    }
+
+    let inliers: Vec<&VerificationAttestation> = round.attestations.iter().filter(|a| !a.is_outlier).collect();
+    require!(inliers.len() >= round.quorum as usize, ErrorCode::QuorumNotReached);
+    require!(
+        round
+            .required_verifier_types
+            .iter()
+            .all(|required| inliers.iter().any(|a| a.verifier_type == *required)),
+        ErrorCode::MissingRequiredVerifierType
+    );
+
+    let verified_carbon_tons = median(&mut inliers.iter().map(|a| a.carbon_tons).collect::<Vec<_>>());
+    let quality_rating = median(&mut inliers.iter().map(|a| a.quality_rating as u64).collect::<Vec<_>>()) as u8;
+    // Report of record: the inlier closest to the aggregate
+    let report_cid = inliers
+        .iter()
+        .min_by_key(|a| a.carbon_tons.abs_diff(verified_carbon_tons))
+        .map(|a| a.report_cid.clone())
+        .unwrap_or_default();
+    let inlier_count = inliers.len();
+
+    let now = Clock::get()?.unix_timestamp;
+    round.finalized = true;
+    round.verified_carbon_tons = verified_carbon_tons;
+    round.quality_rating = quality_rating;
+    round.finalized_at = now;
+    // The escrow is split among the inlier attestations (claim_verification_fee)
+    round.fee_pool = project.verification_fee_lamports;
+    project.verifier = None;
+
+    transition_status(project, VerificationStatus::Verified, ctx.accounts.authority.key())?;
+    project.carbon_tons_estimated = verified_carbon_tons;
+    project.available_quantity = verified_carbon_tons;
+    project.quality_rating = quality_rating;
+    project.verification_data.acva_report_cid = report_cid;
+    project.verification_data.last_verification_date = now;
+    start_challenge_window(project, &ctx.accounts.registry_config)?;
+    start_validity_period(project, &ctx.accounts.registry_config)?;
+
+    ctx.accounts.verification_record.set_inner(VerificationRecord {
+        project: project.key(),
+        index: project.verification_records,
+        verifier: ctx.accounts.authority.key(),
+        decision: VerificationDecision::RoundFinalized,
+        carbon_tons: verified_carbon_tons,
+        quality_rating,
+        report_cid: project.verification_data.acva_report_cid.clone(),
+        reason: String::new(),
+        timestamp: now,
+        fee_paid: 0,
+        bump: ctx.bumps.verification_record,
+    });
+    project.verification_records += 1;
+
+    msg!("✅ Project {} verified by round {}", project.project_id, round.round);
+    msg!("Verified carbon tons: {} (median of {} attestations)", verified_carbon_tons, inlier_count);
+    msg!("Quality rating: {}/5", quality_rating);
+
+    Ok(())
+}
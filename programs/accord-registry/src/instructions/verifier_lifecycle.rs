@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use crate::models::*;
+use crate::instructions::{contexts::*, errors::ErrorCode};
+
+/// Validates a verifier's credentials and specializations against the node's limits.
+pub fn validate_credentials(
+    credentials: &[String],
+    specializations: &[ProjectSector],
+    credentials_expire_at: i64,
+    now: i64,
+) -> Result<()> {
+    require!(
+        !credentials.is_empty() && credentials.len() <= VerificationNode::MAX_CREDENTIALS,
+        ErrorCode::InvalidCredentials
+    );
+    require!(
+        credentials
+            .iter()
+            .all(|c| !c.is_empty() && c.len() <= VerificationNode::MAX_CREDENTIAL_LEN),
+        ErrorCode::InvalidCredentials
+    );
+    require!(
+        !specializations.is_empty() && specializations.len() <= VerificationNode::MAX_SPECIALIZATIONS,
+        ErrorCode::InvalidCredentials
+    );
+    require!(
+        credentials_expire_at == 0 || credentials_expire_at > now,
+        ErrorCode::InvalidCredentials
+    );
+    Ok(())
+}
+
+/// Suspends a verifier until `suspended_until`, or indefinitely when `None`.
+pub fn suspend_verifier(
+    ctx: Context<ManageVerifierStatus>,
+    reason: String,
+    suspended_until: Option<i64>,
+) -> Result<()> {
+    let verifier = &mut ctx.accounts.verifier;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        !reason.is_empty() && reason.len() <= VerificationNode::MAX_SUSPENSION_REASON_LEN,
+        ErrorCode::InvalidSuspension
+    );
+    if let Some(until) = suspended_until {
+        require!(until > now, ErrorCode::InvalidSuspension);
+    }
+
+    verifier.is_active = false;
+    verifier.suspended_until = suspended_until;
+    verifier.suspension_reason = reason;
+
+    msg!("⛔ Verifier {} suspended by {}", verifier.verifier_pubkey, ctx.accounts.admin.key());
+    msg!("Reason: {}", verifier.suspension_reason);
+    match suspended_until {
+        Some(until) => msg!("Suspended until {}", until),
+        None => msg!("Suspended indefinitely"),
+    }
+    Ok(())
+}
+
+pub fn reinstate_verifier(ctx: Context<ManageVerifierStatus>) -> Result<()> {
+    let verifier = &mut ctx.accounts.verifier;
+    require!(!verifier.is_active, ErrorCode::VerifierNotSuspended);
+
+    verifier.is_active = true;
+    verifier.suspended_until = None;
+    verifier.suspension_reason = String::new();
+
+    msg!("✅ Verifier {} reinstated by {}", verifier.verifier_pubkey, ctx.accounts.admin.key());
+    Ok(())
+}
+
+pub fn update_verifier_credentials(
+    ctx: Context<UpdateVerifierCredentials>,
+    credentials: Vec<String>,
+    specializations: Vec<ProjectSector>,
+    credentials_expire_at: i64,
+) -> Result<()> {
+    let verifier = &mut ctx.accounts.verifier;
+    let now = Clock::get()?.unix_timestamp;
+    validate_credentials(&credentials, &specializations, credentials_expire_at, now)?;
+
+    verifier.credentials = credentials;
+    verifier.specializations = specializations;
+    verifier.credentials_expire_at = credentials_expire_at;
+
+    msg!("Verifier {} credentials updated", verifier.verifier_pubkey);
+    msg!("Specializations: {:?}", verifier.specializations);
+    Ok(())
+}
+
+/// Closes the verifier's node and (empty) stake vault. Suspended verifiers
+/// cannot deregister, so a suspension cannot be escaped by re-registering.
+pub fn deregister_verifier(ctx: Context<DeregisterVerifier>) -> Result<()> {
+    let verifier = &ctx.accounts.verifier;
+    require!(verifier.is_active, ErrorCode::VerifierSuspended);
+
+    msg!("Verifier {} deregistered after {} verifications", verifier.verifier_pubkey, verifier.verification_count);
+    Ok(())
+}
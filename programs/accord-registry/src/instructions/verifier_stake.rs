@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::models::*;
+use crate::instructions::{contexts::*, errors::ErrorCode};
+
+/// Moves `amount` lamports out of a program-owned account.
+pub(crate) fn move_lamports(from: &AccountInfo, to: &AccountInfo, amount: u64) -> Result<()> {
+    **from.try_borrow_mut_lamports()? = from
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    **to.try_borrow_mut_lamports()? = to
+        .lamports()
+        .checked_add(amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    Ok(())
+}
+
+/// Transfers `amount` lamports from the verifier into its stake vault.
+pub fn fund_stake<'info>(
+    stake: &mut Account<'info, VerifierStake>,
+    authority: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<()> {
+    let cpi_context = CpiContext::new(
+        system_program.to_account_info(),
+        system_program::Transfer {
+            from: authority.to_account_info(),
+            to: stake.to_account_info(),
+        },
+    );
+    system_program::transfer(cpi_context, amount)?;
+
+    stake.staked = stake.staked.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+    Ok(())
+}
+
+pub fn deposit_stake(ctx: Context<ManageVerifierStake>, amount: u64) -> Result<()> {
+    require!(amount > 0, ErrorCode::InvalidStakeAmount);
+
+    let stake = &mut ctx.accounts.verifier_stake;
+    fund_stake(stake, &ctx.accounts.authority, &ctx.accounts.system_program, amount)?;
+
+    msg!("🔒 Verifier stake deposited: {} lamports (total {})", amount, stake.staked);
+    Ok(())
+}
+
+/// Starts unbonding part of the active stake. Unbonding stake no longer counts
+/// towards project minimums but stays slashable until it is withdrawn.
+pub fn request_stake_unbonding(ctx: Context<ManageVerifierStake>, amount: u64) -> Result<()> {
+    let stake = &mut ctx.accounts.verifier_stake;
+    require!(amount > 0 && amount <= stake.staked, ErrorCode::InvalidStakeAmount);
+
+    let now = Clock::get()?.unix_timestamp;
+    stake.staked -= amount;
+    stake.unbonding = stake.unbonding.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+    // A new request restarts the clock for everything still unbonding
+    stake.unbonding_ends_at = now
+        .checked_add(ctx.accounts.registry_config.stake_unbonding_period)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    msg!("⏳ Unbonding {} lamports until {}", amount, stake.unbonding_ends_at);
+    Ok(())
+}
+
+pub fn withdraw_unbonded_stake(ctx: Context<ManageVerifierStake>) -> Result<()> {
+    let stake = &mut ctx.accounts.verifier_stake;
+    let amount = stake.unbonding;
+    require!(amount > 0, ErrorCode::InvalidStakeAmount);
+    require!(
+        Clock::get()?.unix_timestamp >= stake.unbonding_ends_at,
+        ErrorCode::StakeStillUnbonding
+    );
+
+    move_lamports(
+        &stake.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        amount,
+    )?;
+    stake.unbonding = 0;
+
+    msg!("💸 Withdrew {} lamports of unbonded stake", amount);
+    Ok(())
+}
+
+pub fn set_min_verifier_stake(ctx: Context<SetMinVerifierStake>, amount: u64) -> Result<()> {
+    let project = &mut ctx.accounts.project;
+    project.min_verifier_stake = amount;
+
+    msg!("Project {} requires {} lamports of verifier stake", project.project_id, amount);
+    Ok(())
+}
+
+/// Slashes a verifier, taking from active stake first and then from stake that
+/// is still unbonding. Slashed lamports go to `treasury`. Returns the amount
+/// actually slashed, which may be less than requested.
+pub fn slash_stake(
+    stake: &mut Account<VerifierStake>,
+    verifier: &mut Account<VerificationNode>,
+    treasury: &AccountInfo,
+    slash: &VerifierSlash,
+) -> Result<u64> {
+    let from_staked = slash.amount.min(stake.staked);
+    let from_unbonding = (slash.amount - from_staked).min(stake.unbonding);
+    let slashed = from_staked + from_unbonding;
+
+    move_lamports(&stake.to_account_info(), treasury, slashed)?;
+    stake.staked -= from_staked;
+    stake.unbonding -= from_unbonding;
+    stake.total_slashed = stake.total_slashed.checked_add(slashed).ok_or(ErrorCode::MathOverflow)?;
+    verifier.reputation_score = verifier.reputation_score.saturating_sub(slash.reputation_penalty);
+
+    msg!("⚔️ Slashed verifier {}: {} lamports", stake.authority, slashed);
+    msg!("Reputation score: {}", verifier.reputation_score);
+    Ok(slashed)
+}
@@ -1,51 +1,96 @@
 use anchor_lang::prelude::*;
 use crate::models::*;
-use crate::instructions::{contexts::*, errors::ErrorCode};
+use crate::instructions::{
+    contexts::*,
+    disputes::start_challenge_window,
+    escrow::{pay_from_escrow, start_audit_deadline, EscrowTokenAccounts},
+    validity::start_validity_period,
+    errors::ErrorCode,
+    lifecycle::transition_status,
+    verifier_lifecycle::validate_credentials,
+    verifier_stake::fund_stake,
+};
+
+/// Checks that a verifier may audit `project`: the node is active and
+/// specialized in the project's sector, and the audit cooldown for this
+/// verifier/owner pair has elapsed. Records the audit on success.
+pub fn record_eligible_audit(
+    node: &VerificationNode,
+    audit: &mut VerifierOwnerAudit,
+    project: &Project,
+    config: &RegistryConfig,
+    verifier_authority: Pubkey,
+    bump: u8,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(node.is_active_at(now), ErrorCode::VerifierNotActive);
+    require!(
+        node.is_specialized_in(&project.project_sector),
+        ErrorCode::VerifierNotSpecialized
+    );
+
+    if audit.last_audit_at == 0 {
+        audit.verifier = verifier_authority;
+        audit.owner = project.owner;
+        audit.bump = bump;
+    } else {
+        require!(
+            now >= audit.last_audit_at.saturating_add(config.audit_cooldown),
+            ErrorCode::AuditCooldownActive
+        );
+    }
+    audit.last_audit_at = now;
+    audit.audits += 1;
+
+    Ok(())
+}
 
 pub fn verify_project(
     ctx: Context<VerifyProject>,
     verified_carbon_tons: u64,
 ) -> Result<()> {
     let project = &mut ctx.accounts.project;
-    
-    require!(
-        project.verification_status == VerificationStatus::Pending || 
-        project.verification_status == VerificationStatus::AwaitingAudit,
-        ErrorCode::ProjectAlreadyProcessed
-    );
 
-    project.verification_status = VerificationStatus::Verified;
+    // Validation: Ensure the signer is the assigned verifier
+    if let Some(assigned_verifier) = project.verifier {
+        require!(assigned_verifier == ctx.accounts.admin.key(), ErrorCode::UnauthorizedVerifier);
+    }
+
+    check_verifier_stake(&ctx.accounts.registry_config, project, ctx.accounts.verifier_stake.as_ref())?;
+    transition_status(project, VerificationStatus::Verified, ctx.accounts.admin.key())?;
+    record_eligible_audit(
+        &ctx.accounts.verifier_node,
+        &mut ctx.accounts.verifier_audit,
+        project,
+        &ctx.accounts.registry_config,
+        ctx.accounts.admin.key(),
+        ctx.bumps.verifier_audit,
+    )?;
     project.carbon_tons_estimated = verified_carbon_tons;
     project.available_quantity = verified_carbon_tons;
 
     msg!("Project {} verified successfully!", project.project_id);
     msg!("Verified carbon tons: {}", verified_carbon_tons);
 
-    // Release Escrow if funds are available
-    let fee = project.verification_fee_lamports;
-    if fee > 0 {
-        // Validation: Ensure the signer is the assigned verifier
-        if let Some(assigned_verifier) = project.verifier {
-            require!(assigned_verifier == ctx.accounts.admin.key(), ErrorCode::UnauthorizedVerifier);
-        }
-
-        **project.to_account_info().try_borrow_mut_lamports()? = project
-            .to_account_info()
-            .lamports()
-            .checked_sub(fee)
-            .ok_or(ErrorCode::MathOverflow)?;
-            
-        **ctx.accounts.admin.to_account_info().try_borrow_mut_lamports()? = ctx
-            .accounts.admin
-            .to_account_info()
-            .lamports()
-            .checked_add(fee)
-            .ok_or(ErrorCode::MathOverflow)?;
+    // Escrow stays locked until the challenge window closes (release_verification_fee)
+    project.verifier = Some(ctx.accounts.admin.key());
+    start_challenge_window(project, &ctx.accounts.registry_config)?;
+    start_validity_period(project, &ctx.accounts.registry_config)?;
 
-        project.verification_fee_lamports = 0;
-        project.audit_escrow_balance = 0;
-        msg!("Verification Fee Released: {} lamports", fee);
-    }
+    ctx.accounts.verification_record.set_inner(VerificationRecord {
+        project: project.key(),
+        index: project.verification_records,
+        verifier: ctx.accounts.admin.key(),
+        decision: VerificationDecision::Verified,
+        carbon_tons: verified_carbon_tons,
+        quality_rating: project.quality_rating,
+        report_cid: project.verification_data.acva_report_cid.clone(),
+        reason: String::new(),
+        timestamp: Clock::get()?.unix_timestamp,
+        fee_paid: 0, // Still escrowed; VerificationFeePaid is emitted on release
+        bump: ctx.bumps.verification_record,
+    });
+    project.verification_records += 1;
 
     Ok(())
 }
@@ -55,18 +100,27 @@ pub fn reject_project(
     reason: String,
 ) -> Result<()> {
     let project = &mut ctx.accounts.project;
-    
     require!(
-        project.verification_status == VerificationStatus::Pending || 
-        project.verification_status == VerificationStatus::AwaitingAudit ||
-        project.verification_status == VerificationStatus::UnderReview,
-        ErrorCode::ProjectAlreadyProcessed
+        !reason.is_empty() && reason.len() <= VerificationRecord::MAX_REASON_LEN,
+        ErrorCode::InvalidRejectionReason
     );
-    
+
+    // Validation: If assigned verifier exists, ensure signer is them
+    if let Some(assigned_verifier) = project.verifier {
+        require!(assigned_verifier == ctx.accounts.admin.key(), ErrorCode::UnauthorizedVerifier);
+    }
+    check_verifier_stake(&ctx.accounts.registry_config, project, ctx.accounts.verifier_stake.as_ref())?;
+
     // Update status
-    project.verification_status = VerificationStatus::Rejected;
-    // Note: We are not storing 'reason' in account state to avoid resizing, 
-    // but it is emitted in the transaction logs/events if we added an event.
+    transition_status(project, VerificationStatus::Rejected, ctx.accounts.admin.key())?;
+    record_eligible_audit(
+        &ctx.accounts.verifier_node,
+        &mut ctx.accounts.verifier_audit,
+        project,
+        &ctx.accounts.registry_config,
+        ctx.accounts.admin.key(),
+        ctx.bumps.verifier_audit,
+    )?;
 
     msg!("Project {} rejected by validator {}.", project.project_id, ctx.accounts.admin.key());
     msg!("Rejection Reason: {}", reason);
@@ -74,63 +128,113 @@ pub fn reject_project(
     // Consuming the fee (paying the verifier/admin for their time)
     let fee = project.verification_fee_lamports;
     if fee > 0 {
-        // Validation: If assigned verifier exists, ensure signer is them
-        if let Some(assigned_verifier) = project.verifier {
-            require!(assigned_verifier == ctx.accounts.admin.key(), ErrorCode::UnauthorizedVerifier);
-        }
-
-        **project.to_account_info().try_borrow_mut_lamports()? = project
-            .to_account_info()
-            .lamports()
-            .checked_sub(fee)
-            .ok_or(ErrorCode::MathOverflow)?;
-            
-        **ctx.accounts.admin.to_account_info().try_borrow_mut_lamports()? = ctx
-            .accounts.admin
-            .to_account_info()
-            .lamports()
-            .checked_add(fee)
-            .ok_or(ErrorCode::MathOverflow)?;
-
+        let tokens = EscrowTokenAccounts::from_optional(
+            &ctx.accounts.escrow_vault,
+            &ctx.accounts.escrow_mint,
+            &ctx.accounts.recipient_token_account,
+            &ctx.accounts.token_program,
+        );
+        pay_from_escrow(project, fee, &ctx.accounts.admin.to_account_info(), tokens)?;
         project.verification_fee_lamports = 0;
-        project.audit_escrow_balance = 0;
         msg!("Verification Fee Released (Consumed) for Rejection: {} lamports", fee);
     }
+
+    ctx.accounts.verification_record.set_inner(VerificationRecord {
+        project: project.key(),
+        index: project.verification_records,
+        verifier: ctx.accounts.admin.key(),
+        decision: VerificationDecision::Rejected,
+        carbon_tons: 0,
+        quality_rating: 0,
+        report_cid: String::new(),
+        reason,
+        timestamp: Clock::get()?.unix_timestamp,
+        fee_paid: fee,
+        bump: ctx.bumps.verification_record,
+    });
+    project.verification_records += 1;
     
     Ok(())
 }
 
+/// Records one verifier's attestation in the project's open verification round.
+/// The project is verified only when the round is finalized.
 pub fn multi_party_verify_project(
     ctx: Context<MultiPartyVerifyProject>,
     verified_carbon_tons: u64,
     quality_rating: u8,
-    _verification_report_cid: String,
+    verification_report_cid: String,
 ) -> Result<()> {
     let project = &mut ctx.accounts.project;
+    let round = &mut ctx.accounts.verification_round;
     let verifier = &mut ctx.accounts.verifier;
+    let verifier_authority = ctx.accounts.verifier_authority.key();
     
+    require!((1..=5).contains(&quality_rating), ErrorCode::InvalidQualityRating);
+    require!(verified_carbon_tons > 0, ErrorCode::InvalidCarbonMeasurement);
+    require!(
+        !verification_report_cid.is_empty() && verification_report_cid.len() <= Project::CID_LEN,
+        ErrorCode::InvalidIpfsCid
+    );
+
+    require!(!round.finalized, ErrorCode::VerificationRoundFinalized);
+    require!(round.round + 1 == project.verification_rounds, ErrorCode::VerificationRoundFinalized);
+    require!(
+        round.assigned_verifiers.is_empty() || round.assigned_verifiers.contains(&verifier_authority),
+        ErrorCode::NotAssignedVerifier
+    );
+    require!(
+        round.attestations.iter().all(|a| a.verifier != verifier_authority),
+        ErrorCode::DuplicateAttestation
+    );
     require!(
-        project.verification_status == VerificationStatus::Pending ||
-        project.verification_status == VerificationStatus::UnderReview,
-        ErrorCode::ProjectAlreadyProcessed
+        round.attestations.len() < VerificationRound::MAX_ATTESTATIONS,
+        ErrorCode::TooManyAttestations
     );
 
-    require!(verifier.is_active, ErrorCode::VerifierNotActive);
-    require!(quality_rating >= 1 && quality_rating <= 5, ErrorCode::InvalidQualityRating);
+    record_eligible_audit(
+        verifier,
+        &mut ctx.accounts.verifier_audit,
+        project,
+        &ctx.accounts.registry_config,
+        verifier_authority,
+        ctx.bumps.verifier_audit,
+    )?;
 
-    // Update project verification
-    project.verification_status = VerificationStatus::Verified;
-    project.carbon_tons_estimated = verified_carbon_tons;
-    project.quality_rating = quality_rating;
-    project.available_quantity = verified_carbon_tons;
+    let now = Clock::get()?.unix_timestamp;
+    round.attestations.push(VerificationAttestation {
+        verifier: verifier_authority,
+        verifier_type: verifier.verifier_type.clone(),
+        carbon_tons: verified_carbon_tons,
+        quality_rating,
+        report_cid: verification_report_cid.clone(),
+        submitted_at: now,
+        is_outlier: false,
+        fee_claimed: false,
+    });
+
+    ctx.accounts.verification_record.set_inner(VerificationRecord {
+        project: project.key(),
+        index: project.verification_records,
+        verifier: verifier_authority,
+        decision: VerificationDecision::Attested,
+        carbon_tons: verified_carbon_tons,
+        quality_rating,
+        report_cid: verification_report_cid,
+        reason: String::new(),
+        timestamp: now,
+        fee_paid: 0,
+        bump: ctx.bumps.verification_record,
+    });
+    project.verification_records += 1;
 
     // Update verifier stats
     verifier.verification_count += 1;
-    verifier.reputation_score += 10; // Reward for verification
 
-    msg!("Project {} verified by {:?}", project.project_id, verifier.verifier_type);
-    msg!("Verified carbon tons: {}", verified_carbon_tons);
+    msg!("Project {} attested by {:?}", project.project_id, verifier.verifier_type);
+    msg!("Attested carbon tons: {}", verified_carbon_tons);
     msg!("Quality rating: {}/5", quality_rating);
+    msg!("Attestations: {}/{}", round.attestations.len(), round.quorum);
 
     Ok(())
 }
@@ -138,8 +242,18 @@ pub fn multi_party_verify_project(
 pub fn register_verifier(
     ctx: Context<RegisterVerifier>,
     verifier_data: VerifierData,
+    stake_amount: u64,
 ) -> Result<()> {
     let verifier = &mut ctx.accounts.verifier;
+    let stake = &mut ctx.accounts.verifier_stake;
+    let now = Clock::get()?.unix_timestamp;
+
+    validate_credentials(
+        &verifier_data.credentials,
+        &verifier_data.specializations,
+        verifier_data.credentials_expire_at,
+        now,
+    )?;
 
     verifier.verifier_pubkey = ctx.accounts.verifier_authority.key();
     verifier.verifier_type = verifier_data.verifier_type;
@@ -147,14 +261,42 @@ pub fn register_verifier(
     verifier.reputation_score = 100; // Starting score
     verifier.verification_count = 0;
     verifier.is_active = true;
-    verifier.registration_date = Clock::get()?.unix_timestamp;
+    verifier.registration_date = now;
     verifier.specializations = verifier_data.specializations;
+    verifier.credentials_expire_at = verifier_data.credentials_expire_at;
+    verifier.suspended_until = None;
+    verifier.suspension_reason = String::new();
+
+    stake.authority = ctx.accounts.verifier_authority.key();
+    stake.staked = 0;
+    stake.unbonding = 0;
+    stake.unbonding_ends_at = 0;
+    stake.total_slashed = 0;
+    stake.bump = ctx.bumps.verifier_stake;
+    if stake_amount > 0 {
+        fund_stake(stake, &ctx.accounts.verifier_authority, &ctx.accounts.system_program, stake_amount)?;
+    }
 
     msg!("Verifier registered: {:?}", verifier.verifier_type);
+    msg!("🔒 Initial stake: {} lamports", stake.staked);
 
     Ok(())
 }
 
+/// Verifiers must have at least the registry minimum at risk, or more if the project asks
+pub fn check_verifier_stake(
+    config: &RegistryConfig,
+    project: &Project,
+    stake: Option<&Account<VerifierStake>>,
+) -> Result<()> {
+    let required = config.verifier_stake_for(project);
+    if required > 0 {
+        let stake = stake.ok_or(ErrorCode::InsufficientVerifierStake)?;
+        require!(stake.staked >= required, ErrorCode::InsufficientVerifierStake);
+    }
+    Ok(())
+}
+
 pub fn initialize_verification(
     ctx: Context<InitializeVerification>,
     fee_lamports: u64,
@@ -162,6 +304,24 @@ pub fn initialize_verification(
     let project = &mut ctx.accounts.project;
     let owner = &ctx.accounts.owner;
     let verifier = &ctx.accounts.verifier;
+
+    require!(
+        fee_lamports >= ctx.accounts.registry_config.min_verification_fee,
+        ErrorCode::InsufficientVerificationFee
+    );
+
+    // A stablecoin escrow must be paid out or reclaimed before switching to lamports
+    require!(
+        project.escrow_mint.is_none() || project.verification_fee_lamports == 0,
+        ErrorCode::UnreleasedVerificationFee
+    );
+    // Only an unaudited deposit may be replaced; a decided verification's fee belongs to its verifier
+    let previous_fee = project.verification_fee_lamports;
+    require!(
+        previous_fee == 0 || project.verification_status == VerificationStatus::AwaitingAudit,
+        ErrorCode::UnreleasedVerificationFee
+    );
+    check_verifier_stake(&ctx.accounts.registry_config, project, ctx.accounts.verifier_stake.as_ref())?;
     
     // Transfer SOL from Owner to Project PDA (Escrow)
     let ix = anchor_lang::solana_program::system_instruction::transfer(
@@ -179,11 +339,22 @@ pub fn initialize_verification(
         ],
     )?;
 
+    // Re-assignment refunds the earlier deposit so the escrow only holds the current fee
+    if previous_fee > 0 {
+        pay_from_escrow(project, previous_fee, &owner.to_account_info(), None)?;
+        msg!("↩️ Previous escrow of {} lamports refunded", previous_fee);
+    }
+
     // Update State
     project.verifier = Some(verifier.key());
     project.verification_fee_lamports = fee_lamports;
-    project.audit_escrow_balance += fee_lamports;
-    project.verification_status = VerificationStatus::AwaitingAudit;
+    project.audit_escrow_balance = project
+        .audit_escrow_balance
+        .checked_add(fee_lamports)
+        .ok_or(ErrorCode::MathOverflow)?;
+    project.escrow_mint = None;
+    start_audit_deadline(project, &ctx.accounts.registry_config)?;
+    transition_status(project, VerificationStatus::AwaitingAudit, owner.key())?;
 
     msg!("Verification Escrow Funded: {} lamports", fee_lamports);
     msg!("Assigned Verifier: {}", verifier.key());
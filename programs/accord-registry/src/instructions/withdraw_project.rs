@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, CloseAccount};
+use crate::models::*;
+use crate::instructions::{contexts::*, errors::ErrorCode};
+use crate::instructions::escrow::{pay_from_escrow, EscrowTokenAccounts};
+use crate::instructions::double_counting::update_cells;
+use crate::instructions::register_project::project_h3_cells;
+
+pub fn withdraw_project<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawProject<'info>>,
+) -> Result<()> {
+    let project = &ctx.accounts.project;
+
+    require!(
+        matches!(
+            project.verification_status,
+            VerificationStatus::AwaitingAudit | VerificationStatus::Rejected
+        ),
+        ErrorCode::ProjectNotWithdrawable
+    );
+    // A Rejected project may have been Verified before; its credits must stay backed
+    require!(project.tokens_minted == 0, ErrorCode::ProjectHasMintedCredits);
+
+    // ========================================
+    // Refund and close a stablecoin escrow
+    // ========================================
+    if let Some(escrow_mint) = project.escrow_mint {
+        let tokens = EscrowTokenAccounts::from_optional(
+            &ctx.accounts.escrow_vault,
+            &ctx.accounts.escrow_mint,
+            &ctx.accounts.owner_token_account,
+            &ctx.accounts.token_program,
+        )
+        .ok_or(ErrorCode::MissingEscrowTokenAccounts)?;
+        let vault = tokens.vault.to_account_info();
+        let token_program = tokens.token_program.to_account_info();
+        let refund = tokens.vault.amount;
+
+        let owner = ctx.accounts.owner.to_account_info();
+        let project = &mut ctx.accounts.project;
+        if refund > 0 {
+            pay_from_escrow(project, refund, &owner, Some(tokens))?;
+        }
+
+        let project_id = project.project_id.clone();
+        let seeds = &[b"project".as_ref(), project_id.as_bytes(), &[project.bump]];
+        token_interface::close_account(CpiContext::new_with_signer(
+            token_program,
+            CloseAccount {
+                account: vault,
+                destination: owner,
+                authority: project.to_account_info(),
+            },
+            &[&seeds[..]],
+        ))?;
+        msg!("💰 Escrow Refunded: {} of mint {}", refund, escrow_mint);
+    }
+    let project = &ctx.accounts.project;
+
+    // ========================================
+    // Release the project's H3 cells
+    // ========================================
+    let cells = project_h3_cells(&project.location, project.h3_resolution)?;
+    update_cells(
+        ctx.program_id,
+        &mut ctx.accounts.double_counting_registry,
+        ctx.remaining_accounts,
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &cells,
+        &[],
+    )?;
+
+    let registry = &mut ctx.accounts.registry;
+    registry.total_projects = registry.total_projects.saturating_sub(1);
+
+    // Escrow lamports live on the project PDA and go back to the owner on close
+    msg!("↩️ Project {} withdrawn", project.project_id);
+    msg!("📍 {} H3 cells released", cells.len());
+    if project.escrow_mint.is_none() {
+        msg!("💰 Escrow Refunded: {} lamports", project.audit_escrow_balance);
+    }
+
+    Ok(())
+}
@@ -35,18 +35,84 @@ pub mod blue_carbon_registry {
         instructions::initialize_registry(ctx, decimals)
     }
 
+    /// Creates the registry config with default parameters (admin only, once)
+    /// - Afterwards it can only be changed through an executed `UpdateRegistry`
+    ///   proposal carrying a borsh-encoded `RegistryConfigUpdate`
+    pub fn initialize_registry_config(ctx: Context<InitializeRegistryConfig>) -> Result<()> {
+        instructions::initialize_registry_config(ctx)
+    }
+
     /// Registers a new project on the blockchain (Universal)
     /// Requires:
     /// - Valid ICM Registry ID (mandatory, must equal project_id)
-    /// - Verification fee (minimum 0.1 SOL, held in escrow)
-    pub fn register_project(
-        ctx: Context<RegisterProject>,
+    /// - Verification fee (at least the configured minimum, held in escrow)
+    /// - A preceding ed25519 instruction proving the government authority signed
+    ///   `sha256(borsh(ccts_registry_id, owner, location))`
+    /// - One writable H3 shard PDA per covered parent cell in `remaining_accounts`,
+    ///   ordered by parent cell index (missing shards are created on demand)
+    pub fn register_project<'info>(
+        ctx: Context<'_, '_, '_, 'info, RegisterProject<'info>>,
         project_data: ProjectRegistrationData,
         verification_fee: u64,
     ) -> Result<()> {
         instructions::register_project(ctx, project_data, verification_fee)
     }
 
+    /// Amends a registered project's PDD, estimates, location or price
+    /// - Records the change as a new `ProjectVersion` in the project's history
+    /// - Location changes need a fresh ed25519 government attestation and the
+    ///   writable H3 shard PDAs for both old and new cells in `remaining_accounts`
+    /// - Verified/Monitoring projects go back to UnderReview
+    pub fn amend_project<'info>(
+        ctx: Context<'_, '_, '_, 'info, AmendProject<'info>>,
+        amendment: ProjectAmendmentData,
+    ) -> Result<()> {
+        instructions::amend_project(ctx, amendment)
+    }
+
+    /// Withdraws a project that is still AwaitingAudit or was Rejected
+    /// - Releases its H3 cells (shard PDAs in `remaining_accounts`, sorted)
+    /// - Closes the project PDA, returning unconsumed escrow and rent to the owner
+    pub fn withdraw_project<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawProject<'info>>,
+    ) -> Result<()> {
+        instructions::withdraw_project(ctx)
+    }
+
+    /// Proposes handing the project to `new_owner` (step 1 of 2)
+    /// - Replaces any earlier proposal; `None` cancels it
+    pub fn propose_project_transfer(
+        ctx: Context<ProposeProjectTransfer>,
+        new_owner: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::propose_project_transfer(ctx, new_owner)
+    }
+
+    /// Accepts a pending project transfer as the proposed owner (step 2 of 2)
+    /// - Records the previous owner in a `ProjectOwnershipRecord`
+    pub fn accept_project_transfer(ctx: Context<AcceptProjectTransfer>) -> Result<()> {
+        instructions::accept_project_transfer(ctx)
+    }
+
+    /// Attaches sector-specific measurements to a project (owner only)
+    /// - BlueCarbon/Forestry: biomass and soil pools, species, permanence
+    /// - RenewableEnergy: installed MW and grid emission factor
+    /// - WasteManagement: tonnes diverted
+    pub fn initialize_sector_data(
+        ctx: Context<InitializeSectorData>,
+        details: SectorDetails,
+    ) -> Result<()> {
+        instructions::initialize_sector_data(ctx, details)
+    }
+
+    /// Replaces a project's sector measurements; verified projects go back to UnderReview
+    pub fn update_sector_data(
+        ctx: Context<UpdateSectorData>,
+        details: SectorDetails,
+    ) -> Result<()> {
+        instructions::update_sector_data(ctx, details)
+    }
+
 // Initializing Double Counting Registry
     pub fn initialize_double_counting_registry(
         ctx: Context<InitializeDoubleCountingRegistry>,
@@ -68,6 +134,77 @@ pub mod blue_carbon_registry {
         instructions::verify_project(ctx, verified_carbon_tons)
     }
 
+    /// Disputes a verification within its challenge window, posting `bond`
+    /// lamports and the CID of the supporting evidence. Blocks minting until
+    /// governance resolves it through a `ResolveDispute` proposal.
+    pub fn open_dispute(
+        ctx: Context<OpenDispute>,
+        bond: u64,
+        evidence_cid: String,
+    ) -> Result<()> {
+        instructions::open_dispute(ctx, bond, evidence_cid)
+    }
+
+    /// Pays the escrowed verification fee once the challenge window has closed undisputed
+    pub fn release_verification_fee(ctx: Context<ReleaseVerificationFee>) -> Result<()> {
+        instructions::release_verification_fee(ctx)
+    }
+
+    /// Posts an audit job with a lamport fee budget for a project awaiting audit
+    pub fn post_audit_job(ctx: Context<PostAuditJob>, fee_budget: u64) -> Result<()> {
+        instructions::post_audit_job(ctx, fee_budget)
+    }
+
+    /// Bids on an open audit job (active verifiers specialized in the project's sector)
+    pub fn submit_audit_bid(
+        ctx: Context<SubmitAuditBid>,
+        price: u64,
+        timeline_days: u16,
+    ) -> Result<()> {
+        instructions::submit_audit_bid(ctx, price, timeline_days)
+    }
+
+    /// Accepts a bid: assigns its verifier and funds the escrow at the bid price
+    pub fn accept_audit_bid(ctx: Context<AcceptAuditBid>) -> Result<()> {
+        instructions::accept_audit_bid(ctx)
+    }
+
+    /// Cancels an open audit job (owner only)
+    pub fn cancel_audit_job(ctx: Context<CancelAuditJob>) -> Result<()> {
+        instructions::cancel_audit_job(ctx)
+    }
+
+    /// Withdraws a bid and reclaims its rent
+    pub fn withdraw_audit_bid(ctx: Context<WithdrawAuditBid>) -> Result<()> {
+        instructions::withdraw_audit_bid(ctx)
+    }
+
+    /// Funds the audit escrow in the configured stablecoin instead of lamports
+    pub fn initialize_stable_verification(
+        ctx: Context<InitializeStableVerification>,
+        fee_amount: u64,
+    ) -> Result<()> {
+        instructions::initialize_stable_verification(ctx, fee_amount)
+    }
+
+    /// Returns an unused audit escrow to the owner after the audit deadline
+    pub fn reclaim_verification_escrow(ctx: Context<ReclaimVerificationEscrow>) -> Result<()> {
+        instructions::reclaim_verification_escrow(ctx)
+    }
+
+    /// Moves a project whose verification validity has lapsed to Expired (permissionless crank)
+    pub fn expire_verification(ctx: Context<ExpireVerification>) -> Result<()> {
+        instructions::expire_verification(ctx)
+    }
+
+    /// Renews an Expired project: funds a new audit escrow and returns it to AwaitingAudit
+    pub fn renew_verification(
+        ctx: Context<InitializeVerification>,
+        fee_lamports: u64,
+    ) -> Result<()> {
+        instructions::renew_verification(ctx, fee_lamports)
+    }
+
     /// Rejects a project (consuming fee for validation effort)
     pub fn reject_project(
         ctx: Context<RejectProject>,
@@ -83,7 +220,68 @@ pub mod blue_carbon_registry {
         instructions::verify_project::initialize_verification(ctx, fee_lamports)
     }
 
-    /// Mints carbon credits for verified projects only
+    /// Opens an issuance batch for one monitoring period of a verified project
+    pub fn open_issuance_batch(
+        ctx: Context<OpenIssuanceBatch>,
+        period_start: i64,
+        period_end: i64,
+        vintage_year: u16,
+        claimed_tons: u64,
+        report_cid: String,
+    ) -> Result<()> {
+        instructions::open_issuance_batch(ctx, period_start, period_end, vintage_year, claimed_tons, report_cid)
+    }
+
+    /// Verifies an issuance batch, setting the tonnage that may be minted from it
+    pub fn verify_issuance_batch(ctx: Context<VerifyIssuanceBatch>, verified_tons: u64) -> Result<()> {
+        instructions::verify_issuance_batch(ctx, verified_tons)
+    }
+
+    /// Creates the Token-2022 credit mint, with provenance metadata, for one vintage and
+    /// export class (LoA-backed or domestic-only) of a verified project
+    pub fn create_credit_mint(
+        ctx: Context<CreateCreditMint>,
+        vintage_year: u16,
+        methodology: String,
+        export_authorized: bool,
+    ) -> Result<()> {
+        instructions::create_credit_mint(ctx, vintage_year, methodology, export_authorized)
+    }
+
+    /// Thaws a domestic-only credit account for an attested host-country resident
+    pub fn thaw_credit_account(ctx: Context<ThawCreditAccount>) -> Result<()> {
+        instructions::thaw_credit_account(ctx)
+    }
+
+    /// Updates a key/value metadata field on a credit mint (registry admin only)
+    pub fn update_credit_mint_metadata(
+        ctx: Context<UpdateCreditMintMetadata>,
+        key: String,
+        value: String,
+    ) -> Result<()> {
+        instructions::update_credit_mint_metadata(ctx, key, value)
+    }
+
+    /// Sets a project's risk-assessed buffer rate; None falls back to the sector default (registry admin)
+    pub fn set_project_buffer_rate(ctx: Context<SetProjectBufferRate>, buffer_bps: Option<u16>) -> Result<()> {
+        instructions::set_project_buffer_rate(ctx, buffer_bps)
+    }
+
+    /// Cancels buffer credits to cover a reported reversal (registry admin)
+    pub fn cancel_buffer_credits(
+        ctx: Context<CancelBufferCredits>,
+        amount: u64,
+        evidence_cid: String,
+    ) -> Result<()> {
+        instructions::cancel_buffer_credits(ctx, amount, evidence_cid)
+    }
+
+    /// Releases an issuance's remaining buffer credits to the project owner after its permanence period
+    pub fn release_buffer_credits(ctx: Context<ReleaseBufferCredits>) -> Result<()> {
+        instructions::release_buffer_credits(ctx)
+    }
+
+    /// Mints carbon credits from a verified issuance batch
     pub fn mint_verified_credits(
         ctx: Context<MintVerifiedCredits>, 
         amount: u64
@@ -95,11 +293,6 @@ pub mod blue_carbon_registry {
         instructions::batch_mint_credits(ctx, amounts)
     }
 
-    /// Legacy mint_credits (deprecated - use mint_verified_credits)
-    pub fn mint_credits(ctx: Context<MintCredits>, amount: u64) -> Result<()> {
-        instructions::mint_credits(ctx, amount)
-    }
-
     /// Transfers carbon credits from one token account to another
     pub fn transfer_credits(ctx: Context<TransferCredits>, amount: u64) -> Result<()> {
         instructions::transfer_credits(ctx, amount)
@@ -126,15 +319,99 @@ pub mod blue_carbon_registry {
 
 
 
-    /// Register a verification entity
+    /// Register a verification entity, opening its stake vault with `stake_amount` lamports
     pub fn register_verifier(
         ctx: Context<RegisterVerifier>,
         verifier_data: VerifierData,
+        stake_amount: u64,
+    ) -> Result<()> {
+        instructions::register_verifier(ctx, verifier_data, stake_amount)
+    }
+
+    /// Suspends a verifier (admin only); `suspended_until = None` is indefinite
+    pub fn suspend_verifier(
+        ctx: Context<ManageVerifierStatus>,
+        reason: String,
+        suspended_until: Option<i64>,
     ) -> Result<()> {
-        instructions::register_verifier(ctx, verifier_data)
+        instructions::suspend_verifier(ctx, reason, suspended_until)
     }
 
-    /// Multi-party project verification with enhanced validation
+    /// Lifts a verifier suspension (admin only)
+    pub fn reinstate_verifier(ctx: Context<ManageVerifierStatus>) -> Result<()> {
+        instructions::reinstate_verifier(ctx)
+    }
+
+    /// Replaces the caller's verifier credentials and specializations.
+    /// The node stops being eligible once `credentials_expire_at` passes.
+    pub fn update_verifier_credentials(
+        ctx: Context<UpdateVerifierCredentials>,
+        credentials: Vec<String>,
+        specializations: Vec<ProjectSector>,
+        credentials_expire_at: i64,
+    ) -> Result<()> {
+        instructions::update_verifier_credentials(ctx, credentials, specializations, credentials_expire_at)
+    }
+
+    /// Closes the caller's verifier node and emptied stake vault
+    pub fn deregister_verifier(ctx: Context<DeregisterVerifier>) -> Result<()> {
+        instructions::deregister_verifier(ctx)
+    }
+
+    /// Adds lamports to the caller's verifier stake
+    pub fn deposit_stake(ctx: Context<ManageVerifierStake>, amount: u64) -> Result<()> {
+        instructions::deposit_stake(ctx, amount)
+    }
+
+    /// Starts unbonding verifier stake; it stays slashable until withdrawn
+    pub fn request_stake_unbonding(ctx: Context<ManageVerifierStake>, amount: u64) -> Result<()> {
+        instructions::request_stake_unbonding(ctx, amount)
+    }
+
+    /// Withdraws stake whose unbonding period has elapsed
+    pub fn withdraw_unbonded_stake(ctx: Context<ManageVerifierStake>) -> Result<()> {
+        instructions::withdraw_unbonded_stake(ctx)
+    }
+
+    /// Sets the stake a verifier must hold to audit the project (owner only)
+    pub fn set_min_verifier_stake(ctx: Context<SetMinVerifierStake>, amount: u64) -> Result<()> {
+        instructions::set_min_verifier_stake(ctx, amount)
+    }
+
+    /// Opens a quorum-based verification round for a project (registry admin only)
+    /// - `quorum` must be at least the configured minimum
+    /// - Each of `required_verifier_types` must attest among the inliers
+    /// - Tonnage further than `max_deviation_bps` from the median is an outlier
+    /// - Only `assigned_verifiers` may attest, unless empty
+    pub fn open_verification_round(
+        ctx: Context<OpenVerificationRound>,
+        quorum: u8,
+        required_verifier_types: Vec<VerifierType>,
+        max_deviation_bps: u16,
+        assigned_verifiers: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::open_verification_round(
+            ctx,
+            quorum,
+            required_verifier_types,
+            max_deviation_bps,
+            assigned_verifiers,
+        )
+    }
+
+    /// Pays a verifier its share of a finalized round's escrow once the challenge window closes
+    pub fn claim_verification_fee(ctx: Context<ClaimVerificationFee>) -> Result<()> {
+        instructions::claim_verification_fee(ctx)
+    }
+
+    /// Finalizes the latest verification round once quorum is reached,
+    /// verifying the project at the median of the inlier attestations
+    pub fn finalize_verification_round(ctx: Context<FinalizeVerificationRound>) -> Result<()> {
+        instructions::finalize_verification_round(ctx)
+    }
+
+    /// Multi-party verification: records a verifier's attestation (tons, quality,
+    /// report CID) in the project's open verification round
     pub fn multi_party_verify_project(
         ctx: Context<MultiPartyVerifyProject>,
         verified_carbon_tons: u64,
@@ -186,6 +463,15 @@ pub mod blue_carbon_registry {
         instructions::approve_project_compliance(ctx, ccts_registry_id, authorized_export_limit, loa_issued)
     }
 
+    /// Attests a wallet's country of residence so it can receive domestic-only credits
+    pub fn set_participant_jurisdiction(
+        ctx: Context<SetParticipantJurisdiction>,
+        wallet: Pubkey,
+        country_code: String,
+    ) -> Result<()> {
+        instructions::set_participant_jurisdiction(ctx, wallet, country_code)
+    }
+
     // ========================================
     // DEX & AMM INSTRUCTIONS
     // ========================================
@@ -319,9 +605,9 @@ pub mod blue_carbon_registry {
     }
 
     pub fn fallback<'info>(
-        program_id: &Pubkey,
-        accounts: &'info [AccountInfo<'info>],
-        data: &[u8],
+        _program_id: &Pubkey,
+        _accounts: &'info [AccountInfo<'info>],
+        _data: &[u8],
     ) -> Result<()> {
         // Log fallback execution
         msg!("Fallback: Instruction caught. Ignoring validation for reentrancy test.");
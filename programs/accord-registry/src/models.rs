@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 
 // Universal Project Sectors
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, Default)]
 pub enum ProjectSector {
+    #[default]
     BlueCarbon,      // Mangroves, Seagrass, etc.
     Forestry,        // ARR, REDD+
     RenewableEnergy, // Solar, Wind, Hydro
@@ -11,9 +12,17 @@ pub enum ProjectSector {
     Industrial,
 }
 
+impl ProjectSector {
+    /// Sectors whose stored carbon can be reversed (fire, dieback, land-use change)
+    pub fn is_nature_based(&self) -> bool {
+        matches!(self, ProjectSector::BlueCarbon | ProjectSector::Forestry)
+    }
+}
+
 // Verification Status with enhanced options
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, Default)]
 pub enum VerificationStatus {
+    #[default]
     Pending,
     AwaitingAudit,   // Pre-audit state
     UnderReview,
@@ -23,6 +32,39 @@ pub enum VerificationStatus {
     Expired,
 }
 
+impl VerificationStatus {
+    /// Project lifecycle transition table. Every status change must be listed here.
+    pub fn can_transition_to(&self, next: &VerificationStatus) -> bool {
+        use VerificationStatus::*;
+
+        matches!(
+            (self, next),
+            // Escrow funded (registration or initialize_verification)
+            (Pending, AwaitingAudit)
+                | (AwaitingAudit, AwaitingAudit) // Verifier (re)assignment
+                | (Rejected, AwaitingAudit)      // Resubmission with fresh escrow
+                | (Expired, AwaitingAudit)       // Renewal
+                // Audit in progress
+                | (AwaitingAudit, UnderReview)
+                | (Verified, UnderReview)
+                | (Monitoring, UnderReview)
+                // Audit outcome
+                | (AwaitingAudit, Verified)
+                | (UnderReview, Verified)
+                | (Monitoring, Verified)
+                | (Pending, Rejected)
+                | (AwaitingAudit, Rejected)
+                | (UnderReview, Rejected)
+                | (Monitoring, Rejected)
+                | (Verified, Rejected)           // Verification overturned on dispute
+                // Post-verification
+                | (Verified, Monitoring)
+                | (Verified, Expired)
+                | (Monitoring, Expired)
+        )
+    }
+}
+
 // Verifier types for multi-party verification
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
 pub enum VerifierType {
@@ -48,16 +90,67 @@ pub enum CoBenefit {
     AirQualityImprovement,
 }
 
+// ========================================
+// FIXED-POINT TYPES
+// ========================================
+
+/// Angle in millionths of a degree (~0.11 m at the equator)
+pub type MicroDegrees = i32;
+
+/// Non-negative measurement in thousandths of its unit (e.g. pH 7.25 = 7_250)
+pub type MilliUnits = u64;
+
+/// Measurement that may be negative, in thousandths of its unit
+pub type SignedMilliUnits = i64;
+
+/// Conversion and range helpers for the fixed-point types
+pub mod fixed_point {
+    use super::{MicroDegrees, MilliUnits, SignedMilliUnits};
+
+    pub const MICRO_DEGREES_PER_DEGREE: i32 = 1_000_000;
+    pub const MILLI_PER_UNIT: u64 = 1_000;
+
+    pub const MAX_LATITUDE: MicroDegrees = 90 * MICRO_DEGREES_PER_DEGREE;
+    pub const MAX_LONGITUDE: MicroDegrees = 180 * MICRO_DEGREES_PER_DEGREE;
+
+    /// Percentages and 0-100 scores
+    pub const MAX_PERCENT: MilliUnits = 100 * MILLI_PER_UNIT;
+    pub const MAX_PH: MilliUnits = 14 * MILLI_PER_UNIT;
+    /// Normalized indices such as NDVI span -1.0..=1.0
+    pub const MAX_INDEX: SignedMilliUnits = MILLI_PER_UNIT as SignedMilliUnits;
+
+    pub fn is_valid_latitude(latitude: MicroDegrees) -> bool {
+        (-MAX_LATITUDE..=MAX_LATITUDE).contains(&latitude)
+    }
+
+    pub fn is_valid_longitude(longitude: MicroDegrees) -> bool {
+        (-MAX_LONGITUDE..=MAX_LONGITUDE).contains(&longitude)
+    }
+
+    pub fn to_degrees(value: MicroDegrees) -> f64 {
+        value as f64 / MICRO_DEGREES_PER_DEGREE as f64
+    }
+}
+
 // Geographic location data
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug)]
 pub struct GeoLocation {
-    pub latitude: f64,
-    pub longitude: f64,
-    pub polygon_coordinates: Vec<[f64; 2]>,
+    pub latitude: MicroDegrees,
+    pub longitude: MicroDegrees,
+    pub polygon_coordinates: Vec<[MicroDegrees; 2]>, // Boundary vertices as [latitude, longitude]
     pub country_code: String,
     pub region_name: String,
 }
 
+impl GeoLocation {
+    /// True if the centroid and every boundary vertex are valid coordinates
+    pub fn has_valid_coordinates(&self) -> bool {
+        std::iter::once([self.latitude, self.longitude])
+            .chain(self.polygon_coordinates.iter().copied())
+            .all(|[lat, lng]| fixed_point::is_valid_latitude(lat) && fixed_point::is_valid_longitude(lng))
+    }
+}
+
 // Compliance State for Article 6 & Government Integration
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug)]
 pub struct ComplianceState {
@@ -80,25 +173,25 @@ pub struct VerificationData {
 // Water quality measurements
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct WaterQuality {
-    pub ph_level: f64,
-    pub salinity: f64,
-    pub dissolved_oxygen: f64,
-    pub turbidity: f64,
+    pub ph_level: MilliUnits,
+    pub salinity: MilliUnits,           // milli-PSU
+    pub dissolved_oxygen: MilliUnits,   // micrograms/L
+    pub turbidity: MilliUnits,          // milli-NTU
     pub nutrients: NutrientLevels,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct NutrientLevels {
-    pub nitrogen: f64,
-    pub phosphorus: f64,
-    pub potassium: f64,
+    pub nitrogen: MilliUnits,
+    pub phosphorus: MilliUnits,
+    pub potassium: MilliUnits,
 }
 
 // Tide reading data
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct TideReading {
     pub timestamp: i64,
-    pub tide_height: f64,
+    pub tide_height: SignedMilliUnits,  // mm relative to chart datum
     pub tide_type: String,
 }
 
@@ -107,11 +200,11 @@ pub struct TideReading {
 pub struct SensorReading {
     pub sensor_id: String,
     pub timestamp: i64,
-    pub co2_flux: f64,
-    pub soil_moisture: f64,
-    pub ph_level: f64,
-    pub temperature: f64,
-    pub humidity: f64,
+    pub co2_flux: SignedMilliUnits,
+    pub soil_moisture: MilliUnits,      // milli-percent
+    pub ph_level: MilliUnits,
+    pub temperature: SignedMilliUnits,  // milli-°C
+    pub humidity: MilliUnits,           // milli-percent
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -119,7 +212,7 @@ pub struct CommunityBenefit {
     pub benefit_type: String,
     pub households_affected: u32,
     pub jobs_created: u32,
-    pub income_increase_percentage: f64,
+    pub income_increase_percentage: SignedMilliUnits,
     pub capacity_building_programs: u32,
 }
 
@@ -128,8 +221,8 @@ pub struct EconomicImpact {
     pub direct_revenue: u64,
     pub indirect_benefits: u64,
     pub cost_savings: u64,
-    pub roi_percentage: f64,
-    pub payback_period_years: f64,
+    pub roi_percentage: SignedMilliUnits,
+    pub payback_period_years: MilliUnits,
 }
 
 // Global registry account for tracking overall system state
@@ -158,13 +251,112 @@ impl GlobalRegistry {
         1; // mint_authority_bump
 }
 
+// Tunable protocol parameters, changed only by executing an `UpdateRegistry` multisig proposal
+#[account]
+#[derive(Debug)]
+pub struct RegistryConfig {
+    pub min_verification_fee: u64,      // Lamports escrowed per audit
+    pub h3_resolution: u8,              // Resolution of new double-counting cells
+    pub credit_decimals: u8,            // Decimals of new credit mints; fixed at initialization
+    pub health_threshold: MilliUnits,   // Health score below which verified projects go to Monitoring
+    pub min_verification_quorum: u8,    // Fewest attestations a verification round may require
+    pub stake_unbonding_period: i64,    // Seconds between requesting and withdrawing verifier stake
+    pub challenge_period: i64,          // Seconds after verification during which it can be disputed
+    pub min_dispute_bond: u64,          // Lamports a challenger must post
+    pub audit_cooldown: i64,            // Seconds before a verifier may audit the same owner again
+    pub verification_validity_period: i64, // Seconds a verification stays valid before renewal
+    pub audit_timeout: i64,             // Seconds after funding before the owner may reclaim an unused escrow
+    pub stable_escrow_mint: Option<Pubkey>, // Stablecoin accepted for audit escrow
+    pub min_stable_verification_fee: u64, // Minimum escrow in stablecoin base units
+    pub nature_buffer_bps: u16,         // Share of nature-based issuance withheld into the buffer pool
+    pub permanence_period: i64,         // Seconds after an issuance before its buffer credits are released
+    pub min_verifier_stake: u64,        // Lamports every round attester must have staked, whatever the project asks
+    pub last_proposal_id: Option<u64>,  // Proposal that last changed the config
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl RegistryConfig {
+    pub const LEN: usize =
+        8 + // min_verification_fee
+        1 + // h3_resolution
+        1 + // credit_decimals
+        8 + // health_threshold
+        1 + // min_verification_quorum
+        8 + // stake_unbonding_period
+        8 + // challenge_period
+        8 + // min_dispute_bond
+        8 + // audit_cooldown
+        8 + // verification_validity_period
+        8 + // audit_timeout
+        1 + 32 + // stable_escrow_mint
+        8 + // min_stable_verification_fee
+        2 + // nature_buffer_bps
+        8 + // permanence_period
+        8 + // min_verifier_stake
+        1 + 8 + // last_proposal_id
+        8 + // updated_at
+        1; // bump
+
+    /// Stake a verifier needs to attest for `project`: the project's own
+    /// requirement, but never less than the registry-wide minimum
+    pub fn verifier_stake_for(&self, project: &Project) -> u64 {
+        self.min_verifier_stake.max(project.min_verifier_stake)
+    }
+
+    /// Buffer rate for `project`: its risk-assessed rate, else the sector default
+    pub fn buffer_bps_for(&self, project: &Project) -> u16 {
+        match project.risk_buffer_bps {
+            Some(bps) => bps,
+            None if project.project_sector.is_nature_based() => self.nature_buffer_bps,
+            None => 0,
+        }
+    }
+}
+
+/// Payload of an `UpdateRegistry` proposal; `None` fields are left unchanged
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct RegistryConfigUpdate {
+    pub min_verification_fee: Option<u64>,
+    pub h3_resolution: Option<u8>,
+    pub health_threshold: Option<MilliUnits>,
+    pub min_verification_quorum: Option<u8>,
+    pub stake_unbonding_period: Option<i64>,
+    pub challenge_period: Option<i64>,
+    pub min_dispute_bond: Option<u64>,
+    pub audit_cooldown: Option<i64>,
+    pub verification_validity_period: Option<i64>,
+    pub audit_timeout: Option<i64>,
+    pub stable_escrow_mint: Option<Pubkey>,
+    pub min_stable_verification_fee: Option<u64>,
+    pub nature_buffer_bps: Option<u16>,
+    pub permanence_period: Option<i64>,
+    pub min_verifier_stake: Option<u64>,
+}
+
 // Universal Project Account
 #[account]
 #[derive(Debug, Default)]
 pub struct Project {
     pub project_id: String,
+    pub generation: u32,                // Earlier registrations of this ID; salts child PDA seeds
     pub owner: Pubkey,
+    pub pending_owner: Option<Pubkey>,  // Proposed new owner awaiting acceptance
+    pub ownership_transfers: u32,       // Completed transfers, indexes ProjectOwnershipRecord
+    pub verification_rounds: u32,       // Rounds opened, indexes VerificationRound
+    pub min_verifier_stake: u64,        // Lamports a verifier must have staked to audit this project
+    pub challenge_ends_at: i64,         // End of the post-verification challenge window
+    pub active_dispute: Option<Pubkey>, // Open VerificationDispute; blocks minting
+    pub disputes: u32,                  // Disputes opened, indexes VerificationDispute
+    pub verification_records: u32,     // Decisions recorded, indexes VerificationRecord
+    pub verification_expires_at: i64,   // Verification must be renewed after this; 0 if never verified
+    pub audit_jobs: u32,                // Jobs posted, indexes AuditJob
+    pub issuance_batches: u32,          // Batches opened, indexes IssuanceBatch
+    pub monitored_until: i64,           // End of the latest batch's monitoring period
+    pub risk_buffer_bps: Option<u16>,   // Buffer rate from a risk assessment; None uses the sector default
+    pub export_tokens_issued: u64,      // LoA-backed issuance, capped by compliance.authorized_export_limit
     pub ipfs_cid: String,           // PDD CID
+    pub version: u32,               // PDD version, bumped on every amendment
     pub carbon_tons_estimated: u64,
     pub verification_status: VerificationStatus,
     pub credits_issued: u64,
@@ -174,14 +366,17 @@ pub struct Project {
     // Universal Sectors
     pub project_sector: ProjectSector,
     pub location: GeoLocation,
-    pub area_hectares: f64,         // 0.0 for things like Waste/Industrial
+    pub h3_resolution: u8,          // Resolution its double-counting cells were reserved at
+    pub area_hectares: MilliUnits,  // 0 for things like Waste/Industrial
     pub establishment_date: i64,
     
     // Compliance & Governance
     pub compliance: ComplianceState,
     pub verifier: Option<Pubkey>,       // Assigned Verifier (for fee payout)
-    pub verification_fee_lamports: u64, // Fee agreed/paid
+    pub verification_fee_lamports: u64, // Fee agreed/paid, in escrow_mint base units when set
     pub audit_escrow_balance: u64,      // Locked Audit Fee
+    pub escrow_mint: Option<Pubkey>,    // Stablecoin holding the escrow; None for lamports
+    pub audit_deadline: i64,            // Owner may reclaim the escrow after this if unaudited
     
     // DePIN & Verification Data
     pub verification_data: VerificationData,
@@ -197,22 +392,45 @@ pub struct Project {
 }
 
 impl Project {
+    /// Whether the current verification is still within its validity period
+    pub fn verification_current(&self, now: i64) -> bool {
+        self.verification_expires_at == 0 || now < self.verification_expires_at
+    }
+
     const ID_LEN: usize = 32;
-    const CID_LEN: usize = 46;
+    pub const CID_LEN: usize = 46;
     const COUNTRY_CODE_LEN: usize = 4;
     const REGION_NAME_LEN: usize = 50;
+    pub const MAX_POLYGON_VERTICES: usize = 32;
     
     pub const LEN: usize =
         4 + Self::ID_LEN + // project_id
+        4 + // generation
         32 + // owner
+        1 + 32 + // pending_owner
+        4 + // ownership_transfers
+        4 + // verification_rounds
+        8 + // min_verifier_stake
+        8 + // challenge_ends_at
+        1 + 32 + // active_dispute
+        4 + // disputes
+        4 + // verification_records
+        8 + // verification_expires_at
+        4 + // audit_jobs
+        4 + // issuance_batches
+        8 + // monitored_until
+        1 + 2 + // risk_buffer_bps
+        8 + // export_tokens_issued
         4 + Self::CID_LEN + // ipfs_cid
+        4 + // version
         8 + // carbon_tons_estimated
         1 + // verification_status
         8 + // credits_issued
         8 + // tokens_minted
         1 + // bump
         1 + // project_sector
-        8 + 8 + 4 + 100 + 4 + Self::COUNTRY_CODE_LEN + 4 + Self::REGION_NAME_LEN + // location
+        4 + 4 + 4 + (8 * Self::MAX_POLYGON_VERTICES) + 4 + Self::COUNTRY_CODE_LEN + 4 + Self::REGION_NAME_LEN + // location
+        1 + // h3_resolution
         8 + // area_hectares
         8 + // establishment_date
         // ComplianceState
@@ -225,6 +443,8 @@ impl Project {
         1 + 32 + // verifier (Option<Pubkey>)
         8 + // verification_fee_lamports
         8 + // audit_escrow_balance
+        1 + 32 + // escrow_mint
+        8 + // audit_deadline
         // VerificationData
         4 + 64 + // satellite_data_hash
         4 + 64 + // iot_data_hash
@@ -234,18 +454,423 @@ impl Project {
         2 + 8 + 8;
 }
 
+// Outlives its project so a withdrawn registry ID can be registered again
+#[account]
+pub struct RegistryIdRecord {
+    pub registrations: u32, // Times this ID has been registered, including the current one
+    pub bump: u8,
+}
+
+impl RegistryIdRecord {
+    pub const LEN: usize =
+        4 + // registrations
+        1; // bump
+}
+
+// Sector-specific measurements for BlueCarbon and Forestry projects
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct NatureBasedDetails {
+    pub species_composition: Vec<String>,   // Dominant species
+    pub above_ground_biomass: MilliUnits,   // kgC/ha
+    pub below_ground_biomass: MilliUnits,   // kgC/ha
+    pub soil_carbon_0_30cm: MilliUnits,     // kgC/ha
+    pub soil_carbon_30_100cm: MilliUnits,   // kgC/ha
+    pub permanence_guarantee_years: u16,
+}
+
+// Sector-specific measurements for RenewableEnergy projects
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RenewableEnergyDetails {
+    pub installed_capacity_mw: MilliUnits,  // MW
+    pub grid_emission_factor: MilliUnits,   // kgCO2e/MWh displaced
+}
+
+// Sector-specific measurements for WasteManagement projects
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct WasteManagementDetails {
+    pub tonnes_diverted: u64,               // Per year, from landfill
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub enum SectorDetails {
+    NatureBased(NatureBasedDetails),
+    RenewableEnergy(RenewableEnergyDetails),
+    WasteManagement(WasteManagementDetails),
+}
+
+// Sector extension attached to a Project, readable by verifiers on-chain
+#[account]
+#[derive(Debug)]
+pub struct ProjectSectorData {
+    pub project: Pubkey,
+    pub sector: ProjectSector,
+    pub details: SectorDetails,
+    pub updated_by: Pubkey,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl ProjectSectorData {
+    pub const MAX_SPECIES: usize = 8;
+    pub const SPECIES_NAME_LEN: usize = 32;
+
+    // Largest variant is NatureBased
+    const DETAILS_LEN: usize =
+        1 + // variant tag
+        4 + Self::MAX_SPECIES * (4 + Self::SPECIES_NAME_LEN) + // species_composition
+        8 + 8 + // biomass pools
+        8 + 8 + // soil carbon pools
+        2; // permanence_guarantee_years
+
+    pub const LEN: usize =
+        32 + // project
+        1 + // sector
+        Self::DETAILS_LEN + // details
+        32 + // updated_by
+        8 + // updated_at
+        1; // bump
+}
+
+// One completed change of project ownership
+#[account]
+pub struct ProjectOwnershipRecord {
+    pub project: Pubkey,
+    pub transfer_index: u32,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub accepted_at: i64,
+    pub bump: u8,
+}
+
+impl ProjectOwnershipRecord {
+    pub const LEN: usize =
+        32 + // project
+        4 + // transfer_index
+        32 + // previous_owner
+        32 + // new_owner
+        8 + // accepted_at
+        1; // bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AuditJobStatus {
+    Open,
+    Awarded,
+    Cancelled,
+}
+
+// Audit posted on the job board for verifiers to bid on
+#[account]
+#[derive(Debug)]
+pub struct AuditJob {
+    pub project: Pubkey,
+    pub index: u32,                     // Position in the project's job history
+    pub fee_budget: u64,                // Highest price the owner will accept, in lamports
+    pub status: AuditJobStatus,
+    pub bid_count: u32,
+    pub accepted_bid: Option<Pubkey>,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl AuditJob {
+    pub const LEN: usize =
+        32 + // project
+        4 + // index
+        8 + // fee_budget
+        1 + // status
+        4 + // bid_count
+        1 + 32 + // accepted_bid
+        8 + // created_at
+        1; // bump
+}
+
+// Maps a registry-issued credit mint back to its project and vintage
+#[account]
+#[derive(Debug)]
+pub struct CreditMintInfo {
+    pub mint: Pubkey,
+    pub project: Pubkey,
+    pub project_id: String,
+    pub vintage_year: u16,
+    pub export_authorized: bool,        // LoA-backed; false for domestic-only credits
+    pub country_code: String,           // Host country; domestic-only credits stay with its residents
+    pub serial_prefix: String,          // {country}-{project_id}-{vintage}
+    pub serials_issued: u64,            // Tonnes issued; the last serial allocated
+    pub serials_retired: u64,           // Tonnes retired or cancelled across all issuances
+    pub issuances: u32,                 // Mint calls, indexes IssuanceRecord
+    pub buffer_held: u64,               // Base units held in this mint's buffer vault
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl CreditMintInfo {
+    pub const MAX_METADATA_KEY_LEN: usize = 32;
+    pub const MAX_METADATA_VALUE_LEN: usize = 200;
+    pub const MAX_SERIAL_PREFIX_LEN: usize = 48;
+
+    pub const LEN: usize =
+        32 + // mint
+        32 + // project
+        4 + 32 + // project_id
+        2 + // vintage_year
+        1 + // export_authorized
+        4 + ParticipantJurisdiction::MAX_COUNTRY_CODE_LEN + // country_code
+        4 + Self::MAX_SERIAL_PREFIX_LEN + // serial_prefix
+        8 + // serials_issued
+        8 + // serials_retired
+        4 + // issuances
+        8 + // buffer_held
+        8 + // created_at
+        1; // bump
+}
+
+// Government-attested residence of a wallet, used to keep domestic-only credits at home
+#[account]
+#[derive(Debug)]
+pub struct ParticipantJurisdiction {
+    pub wallet: Pubkey,
+    pub country_code: String,
+    pub attested_by: Pubkey,
+    pub attested_at: i64,
+    pub bump: u8,
+}
+
+impl ParticipantJurisdiction {
+    pub const MAX_COUNTRY_CODE_LEN: usize = 4;
+
+    pub const LEN: usize =
+        32 + // wallet
+        4 + Self::MAX_COUNTRY_CODE_LEN + // country_code
+        32 + // attested_by
+        8 + // attested_at
+        1; // bump
+}
+
+// Contiguous serial range allocated by one mint call
+#[account]
+#[derive(Debug)]
+pub struct IssuanceRecord {
+    pub credit_mint: Pubkey,
+    pub project: Pubkey,
+    pub issuance_batch: Pubkey,
+    pub index: u32,                     // Position in the mint's issuance history
+    pub serial_prefix: String,
+    pub serial_start: u64,              // Inclusive, one serial per tonne
+    pub serial_end: u64,                // Inclusive
+    pub serials_retired: u64,           // Delivered tonnes retired, counted up from serial_start
+    pub amount: u64,                    // In token base units, delivered to recipients
+    pub buffer_amount: u64,             // In token base units, withheld into the buffer vault
+    pub buffer_held: u64,               // Part of buffer_amount still in the vault (not cancelled or released)
+    pub buffer_cancelled: u64,          // Part of buffer_amount cancelled for reversals, serials taken down from serial_end
+    pub permanence_ends_at: i64,        // This issuance's buffer credits are released after this
+    pub issued_at: i64,
+    pub bump: u8,
+}
+
+impl IssuanceRecord {
+    pub const LEN: usize =
+        32 + // credit_mint
+        32 + // project
+        32 + // issuance_batch
+        4 + // index
+        4 + CreditMintInfo::MAX_SERIAL_PREFIX_LEN + // serial_prefix
+        8 + // serial_start
+        8 + // serial_end
+        8 + // serials_retired
+        8 + // amount
+        8 + // buffer_amount
+        8 + // buffer_held
+        8 + // buffer_cancelled
+        8 + // permanence_ends_at
+        8 + // issued_at
+        1; // bump
+}
+
+// Serial range cancelled by one retirement
+#[account]
+#[derive(Debug)]
+pub struct RetirementRecord {
+    pub owner: Pubkey,
+    pub credit_mint: Pubkey,
+    pub project: Pubkey,
+    pub certificate_mint: Pubkey,
+    pub retirement_id: String,
+    pub serial_prefix: String,
+    pub serial_start: u64,              // Inclusive
+    pub serial_end: u64,                // Inclusive
+    pub amount: u64,                    // In token base units
+    pub retired_at: i64,
+    pub bump: u8,
+}
+
+impl RetirementRecord {
+    pub const MAX_RETIREMENT_ID_LEN: usize = 32;
+
+    pub const LEN: usize =
+        32 + // owner
+        32 + // credit_mint
+        32 + // project
+        32 + // certificate_mint
+        4 + Self::MAX_RETIREMENT_ID_LEN + // retirement_id
+        4 + CreditMintInfo::MAX_SERIAL_PREFIX_LEN + // serial_prefix
+        8 + // serial_start
+        8 + // serial_end
+        8 + // amount
+        8 + // retired_at
+        1; // bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum IssuanceBatchStatus {
+    Pending,
+    Verified,
+}
+
+// Credits issued for one monitoring/reporting period of a project
+#[account]
+#[derive(Debug)]
+pub struct IssuanceBatch {
+    pub project: Pubkey,
+    pub index: u32,                     // Position in the project's batch history
+    pub period_start: i64,
+    pub period_end: i64,
+    pub vintage_year: u16,
+    pub claimed_tons: u64,              // Reductions reported by the owner
+    pub verified_tons: u64,             // Tonnage cap for minting, set on verification
+    pub tokens_minted: u64,             // In token base units
+    pub report_cid: String,             // Monitoring report
+    pub status: IssuanceBatchStatus,
+    pub verified_by: Option<Pubkey>,
+    pub verified_at: i64,
+    pub bump: u8,
+}
+
+impl IssuanceBatch {
+    pub const LEN: usize =
+        32 + // project
+        4 + // index
+        8 + // period_start
+        8 + // period_end
+        2 + // vintage_year
+        8 + // claimed_tons
+        8 + // verified_tons
+        8 + // tokens_minted
+        4 + Project::CID_LEN + // report_cid
+        1 + // status
+        1 + 32 + // verified_by
+        8 + // verified_at
+        1; // bump
+}
+
+// A verifier's offer to perform an audit job
+#[account]
+#[derive(Debug)]
+pub struct AuditBid {
+    pub audit_job: Pubkey,
+    pub verifier: Pubkey,               // Verifier authority
+    pub price: u64,                     // Lamports
+    pub timeline_days: u16,             // Committed time to complete the audit
+    pub submitted_at: i64,
+    pub bump: u8,
+}
+
+impl AuditBid {
+    pub const LEN: usize =
+        32 + // audit_job
+        32 + // verifier
+        8 + // price
+        2 + // timeline_days
+        8 + // submitted_at
+        1; // bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VerificationDecision {
+    Verified,           // Single-party verification
+    Rejected,
+    Attested,           // One attestation within a verification round
+    RoundFinalized,     // Aggregate outcome of a verification round
+    BatchVerified,      // Tonnage verified for one issuance batch
+}
+
+// One entry in a project's append-only verification history
+#[account]
+#[derive(Debug)]
+pub struct VerificationRecord {
+    pub project: Pubkey,
+    pub index: u32,                     // Position in the project's verification history
+    pub verifier: Pubkey,               // Signer who made the decision
+    pub decision: VerificationDecision,
+    pub carbon_tons: u64,
+    pub quality_rating: u8,
+    pub report_cid: String,
+    pub reason: String,                 // Rejection reason, empty otherwise
+    pub timestamp: i64,
+    pub fee_paid: u64,                  // Escrowed lamports attached to this decision
+    pub bump: u8,
+}
+
+impl VerificationRecord {
+    pub const MAX_REASON_LEN: usize = 200;
+
+    pub const LEN: usize =
+        32 + // project
+        4 + // index
+        32 + // verifier
+        1 + // decision
+        8 + // carbon_tons
+        1 + // quality_rating
+        4 + Project::CID_LEN + // report_cid
+        4 + Self::MAX_REASON_LEN + // reason
+        8 + // timestamp
+        8 + // fee_paid
+        1; // bump
+}
+
+// One entry in a project's amendment history
+#[account]
+pub struct ProjectVersion {
+    pub project: Pubkey,
+    pub version: u32,                           // Version this amendment produced
+    pub old_ipfs_cid: String,
+    pub new_ipfs_cid: String,
+    pub old_carbon_tons_estimated: u64,
+    pub new_carbon_tons_estimated: u64,
+    pub location_changed: bool,
+    pub previous_status: VerificationStatus,
+    pub amended_by: Pubkey,
+    pub amended_at: i64,
+    pub bump: u8,
+}
+
+impl ProjectVersion {
+    pub const LEN: usize =
+        32 + // project
+        4 + // version
+        4 + Project::CID_LEN + // old_ipfs_cid
+        4 + Project::CID_LEN + // new_ipfs_cid
+        8 + // old_carbon_tons_estimated
+        8 + // new_carbon_tons_estimated
+        1 + // location_changed
+        1 + // previous_status
+        32 + // amended_by
+        8 + // amended_at
+        1; // bump
+}
+
 // Carbon measurement data structure
 #[account]
 pub struct CarbonMeasurement {
     pub project_id: String,
     pub measurement_date: i64,
-    pub above_ground_biomass: f64,
-    pub below_ground_biomass: f64,
-    pub soil_carbon_0_30cm: f64,
-    pub soil_carbon_30_100cm: f64,
-    pub sequestration_rate_annual: f64,
+    pub above_ground_biomass: MilliUnits,
+    pub below_ground_biomass: MilliUnits,
+    pub soil_carbon_0_30cm: MilliUnits,
+    pub soil_carbon_30_100cm: MilliUnits,
+    pub sequestration_rate_annual: MilliUnits,
     pub methodology: String,
-    pub uncertainty_percentage: f64,
+    pub uncertainty_percentage: MilliUnits,
     pub measurer_authority: Pubkey,
     pub verification_status: VerificationStatus,
     pub satellite_imagery_cid: String,
@@ -260,9 +885,191 @@ pub struct VerificationNode {
     pub credentials: Vec<String>,
     pub reputation_score: u64,
     pub verification_count: u64,
-    pub is_active: bool,
+    pub is_active: bool,                // false while suspended
     pub registration_date: i64,
     pub specializations: Vec<ProjectSector>,
+    pub credentials_expire_at: i64,     // 0 if the credentials do not expire
+    pub suspended_until: Option<i64>,   // None with is_active == false is an indefinite suspension
+    pub suspension_reason: String,
+}
+
+impl VerificationNode {
+    pub const MAX_CREDENTIALS: usize = 8;
+    pub const MAX_CREDENTIAL_LEN: usize = 64;
+    pub const MAX_SPECIALIZATIONS: usize = 6;
+    pub const MAX_SUSPENSION_REASON_LEN: usize = 100;
+
+    pub fn is_specialized_in(&self, sector: &ProjectSector) -> bool {
+        self.specializations.contains(sector)
+    }
+
+    /// Whether the verifier may audit at `now`: not suspended (or the
+    /// suspension has lapsed) and holding unexpired credentials
+    pub fn is_active_at(&self, now: i64) -> bool {
+        let suspension_over = self.is_active || self.suspended_until.is_some_and(|until| now >= until);
+        let credentials_valid = self.credentials_expire_at == 0 || now < self.credentials_expire_at;
+        suspension_over && credentials_valid
+    }
+}
+
+// Last time a verifier audited one of an owner's projects; enforces the audit cooldown
+#[account]
+#[derive(Debug)]
+pub struct VerifierOwnerAudit {
+    pub verifier: Pubkey,               // Verifier authority
+    pub owner: Pubkey,                  // Project owner
+    pub last_audit_at: i64,             // 0 until the first audit
+    pub audits: u32,
+    pub bump: u8,
+}
+
+impl VerifierOwnerAudit {
+    pub const LEN: usize =
+        32 + // verifier
+        32 + // owner
+        8 + // last_audit_at
+        4 + // audits
+        1; // bump
+}
+
+// Lamports a verifier has put at risk; held by this PDA and slashable through governance
+#[account]
+#[derive(Debug)]
+pub struct VerifierStake {
+    pub authority: Pubkey,              // Verifier authority
+    pub staked: u64,                    // Active stake
+    pub unbonding: u64,                 // Requested for withdrawal, still slashable
+    pub unbonding_ends_at: i64,
+    pub total_slashed: u64,
+    pub bump: u8,
+}
+
+impl VerifierStake {
+    pub const LEN: usize =
+        32 + // authority
+        8 + // staked
+        8 + // unbonding
+        8 + // unbonding_ends_at
+        8 + // total_slashed
+        1; // bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DisputeStatus {
+    Open,
+    Upheld,       // Verification stands, bond slashed
+    Overturned,   // Verification reversed, bond refunded
+}
+
+// Challenge to a project's verification, raised within its challenge window
+#[account]
+#[derive(Debug)]
+pub struct VerificationDispute {
+    pub project: Pubkey,
+    pub challenger: Pubkey,
+    pub index: u32,                     // Position in the project's dispute history
+    pub bond: u64,                      // Lamports held by this PDA until resolution
+    pub evidence_cid: String,
+    pub status: DisputeStatus,
+    pub opened_at: i64,
+    pub resolved_at: i64,
+    pub bump: u8,
+}
+
+impl VerificationDispute {
+    pub const LEN: usize =
+        32 + // project
+        32 + // challenger
+        4 + // index
+        8 + // bond
+        4 + Project::CID_LEN + // evidence_cid
+        1 + // status
+        8 + // opened_at
+        8 + // resolved_at
+        1; // bump
+}
+
+/// Payload of a `ResolveDispute` proposal; the proposal target is the dispute
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct DisputeResolution {
+    pub uphold: bool,                   // true keeps the verification and slashes the bond
+}
+
+/// Payload of a `SlashVerifier` proposal; the proposal target is the verifier authority
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct VerifierSlash {
+    pub amount: u64,                    // Lamports, taken from active stake first
+    pub reputation_penalty: u64,
+}
+
+// One verifier's independent assessment within a verification round
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct VerificationAttestation {
+    pub verifier: Pubkey,               // Verifier authority
+    pub verifier_type: VerifierType,
+    pub carbon_tons: u64,
+    pub quality_rating: u8,
+    pub report_cid: String,
+    pub submitted_at: i64,
+    pub is_outlier: bool,               // Set at finalization
+    pub fee_claimed: bool,
+}
+
+impl VerificationAttestation {
+    pub const LEN: usize =
+        32 + // verifier
+        1 + // verifier_type
+        8 + // carbon_tons
+        1 + // quality_rating
+        4 + Project::CID_LEN + // report_cid
+        8 + // submitted_at
+        1 + // is_outlier
+        1; // fee_claimed
+}
+
+// Quorum-based multi-party verification of a project
+#[account]
+#[derive(Debug)]
+pub struct VerificationRound {
+    pub project: Pubkey,
+    pub round: u32,
+    pub opened_by: Pubkey,
+    pub quorum: u8,                                 // Inlier attestations needed to finalize
+    pub required_verifier_types: Vec<VerifierType>, // Each must appear among the inliers
+    pub max_deviation_bps: u16,                     // Distance from the median beyond which tons are outliers
+    pub assigned_verifiers: Vec<Pubkey>,            // Only these may attest; empty means open
+    pub attestations: Vec<VerificationAttestation>,
+    pub finalized: bool,
+    pub verified_carbon_tons: u64,                  // Median of inlier attestations
+    pub quality_rating: u8,
+    pub fee_pool: u64,                              // Escrow split among inlier attestations
+    pub fee_paid: u64,
+    pub opened_at: i64,
+    pub finalized_at: i64,
+    pub bump: u8,
+}
+
+impl VerificationRound {
+    pub const MAX_ATTESTATIONS: usize = 10;
+    pub const MAX_REQUIRED_TYPES: usize = 4;
+
+    pub const LEN: usize =
+        32 + // project
+        4 + // round
+        32 + // opened_by
+        1 + // quorum
+        4 + Self::MAX_REQUIRED_TYPES + // required_verifier_types
+        2 + // max_deviation_bps
+        4 + Self::MAX_ATTESTATIONS * 32 + // assigned_verifiers
+        4 + Self::MAX_ATTESTATIONS * VerificationAttestation::LEN + // attestations
+        1 + // finalized
+        8 + // verified_carbon_tons
+        1 + // quality_rating
+        8 + // fee_pool
+        8 + // fee_paid
+        8 + // opened_at
+        8 + // finalized_at
+        1; // bump
 }
 
 // Environmental monitoring data
@@ -271,12 +1078,12 @@ pub struct MonitoringData {
     pub project_id: String,
     pub timestamp: i64,
     pub satellite_imagery_cid: String,
-    pub ndvi_index: f64,
+    pub ndvi_index: SignedMilliUnits,
     pub water_quality: WaterQuality,
-    pub temperature_data: Vec<f64>,
+    pub temperature_data: Vec<SignedMilliUnits>,
     pub tide_data: Vec<TideReading>,
     pub iot_sensor_data: Vec<SensorReading>,
-    pub ecosystem_health_score: f64,
+    pub ecosystem_health_score: MilliUnits,
 }
 
 // Marketplace listing for carbon credits
@@ -304,7 +1111,7 @@ impl CarbonCreditListing {
         8 + // quantity_available
         8 + // price_per_ton
         1 + // quality_rating
-        4 + (1 * 10) + // co_benefits vec (approx)
+        4 + 10 + // co_benefits vec (approx)
         4 + (32 * 5) + // certification_standards vec
         32 + // currency_mint
         8 + // listing_date
@@ -318,28 +1125,15 @@ pub struct ImpactReport {
     pub project_id: String,
     pub reporting_period_start: i64,
     pub reporting_period_end: i64,
-    pub carbon_sequestered: f64,
-    pub ecosystem_health_improvement: f64,
-    pub biodiversity_increase: f64,
+    pub carbon_sequestered: MilliUnits,             // kg (milli-tonnes)
+    pub ecosystem_health_improvement: SignedMilliUnits,
+    pub biodiversity_increase: SignedMilliUnits,
     pub community_benefits: Vec<CommunityBenefit>,
     pub economic_impact: EconomicImpact,
     pub sdg_contributions: Vec<u8>,
     pub verification_report_cid: String,
 }
 
-// Default implementations
-impl Default for VerificationStatus {
-    fn default() -> Self {
-        VerificationStatus::Pending
-    }
-}
-
-impl Default for ProjectSector {
-    fn default() -> Self {
-        ProjectSector::BlueCarbon
-    }
-}
-
 // Input data structures for enhanced functions
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct ProjectRegistrationData {
@@ -348,7 +1142,7 @@ pub struct ProjectRegistrationData {
     pub carbon_tons_estimated: u64,
     pub project_sector: ProjectSector,
     pub location: GeoLocation,
-    pub area_hectares: f64,
+    pub area_hectares: MilliUnits,
     pub establishment_date: i64,
     pub vintage_year: u16,
     pub price_per_ton: u64,
@@ -356,6 +1150,17 @@ pub struct ProjectRegistrationData {
     pub compliance_id_signature: Vec<u8>, // Signature from Gov Authority verifying ID
 }
 
+/// Fields a developer may revise after registration; `None` leaves a field unchanged
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ProjectAmendmentData {
+    pub ipfs_cid: Option<String>,
+    pub carbon_tons_estimated: Option<u64>,
+    pub location: Option<GeoLocation>,
+    pub area_hectares: Option<MilliUnits>,
+    pub price_per_ton: Option<u64>,
+    pub compliance_id_signature: Vec<u8>, // Fresh Gov Authority signature, required when location changes
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct BlueProjectData {
     pub project_id: String,
@@ -363,22 +1168,22 @@ pub struct BlueProjectData {
     pub carbon_tons_estimated: u64,
     pub project_sector: ProjectSector,
     pub location: GeoLocation,
-    pub area_hectares: f64,
+    pub area_hectares: MilliUnits,
     pub species_composition: Vec<String>,
-    pub biodiversity_index: f64,
-    pub above_ground_biomass: f64,
-    pub below_ground_biomass: f64,
-    pub soil_carbon_0_30cm: f64,
-    pub soil_carbon_30_100cm: f64,
-    pub sequestration_rate_annual: f64,
+    pub biodiversity_index: MilliUnits,
+    pub above_ground_biomass: MilliUnits,
+    pub below_ground_biomass: MilliUnits,
+    pub soil_carbon_0_30cm: MilliUnits,
+    pub soil_carbon_30_100cm: MilliUnits,
+    pub sequestration_rate_annual: MilliUnits,
     pub measurement_methodology: String,
-    pub uncertainty_percentage: f64,
+    pub uncertainty_percentage: MilliUnits,
     pub vcs_methodology: String,
     pub additionality_proof_cid: String,
     pub permanence_guarantee_years: u16,
-    pub leakage_assessment: f64,
+    pub leakage_assessment: MilliUnits,
     pub monitoring_plan_cid: String,
-    pub baseline_ecosystem_health: f64,
+    pub baseline_ecosystem_health: MilliUnits,
     pub species_count_baseline: u32,
     pub co_benefits: Vec<CoBenefit>,
     pub vintage_year: u16,
@@ -390,18 +1195,19 @@ pub struct VerifierData {
     pub verifier_type: VerifierType,
     pub credentials: Vec<String>,
     pub specializations: Vec<ProjectSector>,
+    pub credentials_expire_at: i64,     // 0 if the credentials do not expire
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct MonitoringDataInput {
     pub project_id: String,
     pub satellite_imagery_cid: String,
-    pub ndvi_index: f64,
+    pub ndvi_index: SignedMilliUnits,
     pub water_quality: WaterQuality,
-    pub temperature_data: Vec<f64>,
+    pub temperature_data: Vec<SignedMilliUnits>,
     pub tide_data: Vec<TideReading>,
     pub iot_sensor_data: Vec<SensorReading>,
-    pub ecosystem_health_score: f64,
+    pub ecosystem_health_score: MilliUnits,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -420,8 +1226,8 @@ pub struct ImpactReportData {
     pub project_id: String,
     pub reporting_period_start: i64,
     pub reporting_period_end: i64,
-    pub carbon_sequestered: f64,
-    pub ecosystem_health_improvement: f64,
+    pub carbon_sequestered: MilliUnits,
+    pub ecosystem_health_improvement: SignedMilliUnits,
     pub community_benefits: Vec<CommunityBenefit>,
     pub economic_impact: EconomicImpact,
     pub sdg_contributions: Vec<u8>,
@@ -434,8 +1240,9 @@ pub struct ImpactReportData {
 // ========================================
 
 /// User roles for on-chain access control
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, Default)]
 pub enum UserRole {
+    #[default]
     None,           // Default - no special permissions
     User,           // Can register projects
     Validator,      // Can verify projects
@@ -444,12 +1251,6 @@ pub enum UserRole {
     SuperAdmin,     // Can manage other admins
 }
 
-impl Default for UserRole {
-    fn default() -> Self {
-        UserRole::None
-    }
-}
-
 /// On-chain user account storing role and permissions
 #[account]
 #[derive(Debug)]
@@ -524,6 +1325,8 @@ pub enum ProposalType {
     EmergencyPause,     // Pause system operations
     TransferAuthority,  // Transfer registry ownership
     UpdateThreshold,    // Change approval threshold
+    SlashVerifier,      // Slash a verifier's stake
+    ResolveDispute,     // Uphold or overturn a disputed verification
 }
 
 /// Audit log entry for tracking all admin actions
@@ -659,18 +1462,68 @@ impl LiquidityPool {
         1;   // bump
 }
 
-// Double Counting Prevention Registry
+// Double Counting Prevention Registry (root of the sharded H3 cell index)
 #[account]
 pub struct DoubleCountingRegistry {
-    pub registered_locations: Vec<u64>, // List of H3 cell indices that are already registered
     pub authority: Pubkey,              // Authority to manage this (e.g. Registry Admin)
+    pub total_shards: u64,              // Number of shard accounts created so far
+    pub total_cells: u64,               // H3 cells currently reserved across all shards
     pub bump: u8,
 }
 
 impl DoubleCountingRegistry {
-    // 8 (discriminator) + 4 (vec len) + (8 * 500) (cells) + 32 (authority) + 1 (bump)
-    // Total: 8 + 4 + 4000 + 32 + 1 = 4045 bytes (fits in 10KB limit)
-    pub const LEN: usize = 8 + 4 + (8 * 500) + 32 + 1; 
+    // 8 (discriminator) + 32 (authority) + 8 (total_shards) + 8 (total_cells) + 1 (bump)
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 1;
+}
+
+// One shard per coarse H3 parent cell, created on demand at registration
+#[account]
+pub struct DoubleCountingShard {
+    pub parent_cell: u64,                       // Coarse H3 cell this shard covers
+    pub occupancy: [u8; 43],                    // Bit per child cell (343 bits), indexed by H3 child position
+    pub occupied_cells: u16,                    // Cells currently reserved
+    pub peak_occupied_cells: u16,               // Highest occupancy ever reached
+    pub total_reservations: u64,                // Cells reserved over the shard's lifetime
+    pub total_releases: u64,                    // Cells released over the shard's lifetime
+    pub created_at: i64,
+    pub last_updated: i64,
+    pub bump: u8,
+}
+
+impl DoubleCountingShard {
+    /// 7^3 children per parent, three resolutions down
+    pub const MAX_CELLS: usize = 343;
+    pub const BITMAP_LEN: usize = Self::MAX_CELLS.div_ceil(8);
+
+    pub const LEN: usize =
+        8 + // parent_cell
+        Self::BITMAP_LEN + // occupancy
+        2 + // occupied_cells
+        2 + // peak_occupied_cells
+        8 + // total_reservations
+        8 + // total_releases
+        8 + // created_at
+        8 + // last_updated
+        1; // bump
+
+    pub fn is_occupied(&self, position: usize) -> bool {
+        self.occupancy[position / 8] & (1 << (position % 8)) != 0
+    }
+
+    pub fn occupy(&mut self, position: usize) {
+        if !self.is_occupied(position) {
+            self.occupancy[position / 8] |= 1 << (position % 8);
+            self.occupied_cells += 1;
+            self.peak_occupied_cells = self.peak_occupied_cells.max(self.occupied_cells);
+        }
+    }
+
+    pub fn release(&mut self, position: usize) {
+        if self.is_occupied(position) {
+            self.occupancy[position / 8] &= !(1 << (position % 8));
+            self.occupied_cells -= 1;
+        }
+    }
 }
 
 // Platform Analytics for Dashboard
@@ -5,6 +5,8 @@ import {
   PublicKey,
   Keypair,
   SystemProgram,
+  Ed25519Program,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
 } from "@solana/web3.js";
 import {
   TOKEN_PROGRAM_ID,
@@ -14,8 +16,93 @@ import {
   getOrCreateAssociatedTokenAccount,
   getAssociatedTokenAddress,
   getAccount,
+  getMint,
+  getTokenMetadata,
+  transferChecked,
 } from "@solana/spl-token";
 import { assert } from "chai";
+import { cellToParent, latLngToCell, polygonToCells } from "h3-js";
+import { createHash } from "crypto";
+
+const H3_RESOLUTION = 8;
+const SHARD_RESOLUTION = H3_RESOLUTION - 3;
+
+// Coordinates are stored on-chain as micro-degrees, measurements as milli-units
+const microDegrees = (degrees: number) => Math.round(degrees * 1_000_000);
+const milli = (units: number) => new anchor.BN(Math.round(units * 1_000));
+
+// Shard PDAs covering a project location, ordered by parent cell as the program expects
+function h3ShardAccounts(
+  programId: PublicKey,
+  location: { latitude: number; longitude: number; polygonCoordinates: number[][] }
+) {
+  const degrees = (value: number) => value / 1_000_000;
+  const cells = new Set<string>([
+    latLngToCell(degrees(location.latitude), degrees(location.longitude), H3_RESOLUTION),
+  ]);
+  if (location.polygonCoordinates.length >= 3) {
+    const boundary = location.polygonCoordinates.map(([lat, lng]) => [degrees(lat), degrees(lng)]);
+    polygonToCells(boundary, H3_RESOLUTION).forEach((cell) => cells.add(cell));
+  }
+
+  const parents = Array.from(new Set(Array.from(cells).map((cell) => BigInt(`0x${cellToParent(cell, SHARD_RESOLUTION)}`))));
+  parents.sort((a, b) => (a < b ? -1 : a > b ? 1 : 0));
+
+  return parents.map((parent) => {
+    const seed = Buffer.alloc(8);
+    seed.writeBigUInt64LE(parent);
+    const [pubkey] = PublicKey.findProgramAddressSync([Buffer.from("h3_shard"), seed], programId);
+    return { pubkey, isWritable: true, isSigner: false };
+  });
+}
+
+// Government attestation: ed25519 over sha256(borsh(ccts_registry_id, owner, location))
+function complianceIdSignature(
+  government: Keypair,
+  cctsRegistryId: string,
+  owner: PublicKey,
+  location: {
+    latitude: number;
+    longitude: number;
+    polygonCoordinates: number[][];
+    countryCode: string;
+    regionName: string;
+  }
+) {
+  const borshString = (value: string) => {
+    const bytes = Buffer.from(value);
+    const len = Buffer.alloc(4);
+    len.writeUInt32LE(bytes.length);
+    return Buffer.concat([len, bytes]);
+  };
+  const borshI32 = (value: number) => {
+    const buf = Buffer.alloc(4);
+    buf.writeInt32LE(value);
+    return buf;
+  };
+  const polygonLen = Buffer.alloc(4);
+  polygonLen.writeUInt32LE(location.polygonCoordinates.length);
+
+  const payload = Buffer.concat([
+    borshString(cctsRegistryId),
+    owner.toBuffer(),
+    borshI32(location.latitude),
+    borshI32(location.longitude),
+    polygonLen,
+    ...location.polygonCoordinates.flatMap(([lat, lng]) => [borshI32(lat), borshI32(lng)]),
+    borshString(location.countryCode),
+    borshString(location.regionName),
+  ]);
+  const message = createHash("sha256").update(payload).digest();
+
+  const ix = Ed25519Program.createInstructionWithPrivateKey({
+    privateKey: government.secretKey,
+    message,
+  });
+  // Signature sits after the 2-byte header, 14-byte offsets and 32-byte public key
+  const signature = Buffer.from(ix.data.subarray(48, 112));
+  return { ix, signature };
+}
 
 describe("blue-carbon-registry", () => {
   const provider = anchor.AnchorProvider.env();
@@ -29,12 +116,118 @@ describe("blue-carbon-registry", () => {
   let projectTokenAccount: PublicKey;
   let investorTokenAccount: PublicKey;
   let investorWallet: Keypair;
+  let validator: Keypair;
   let retirementAccount: PublicKey;
   let doubleCountingRegistryPda: PublicKey;
+  let registryConfigPda: PublicKey;
+  let issuanceBatchPda: PublicKey;
+  let creditMint: PublicKey;
+  let creditMintInfoPda: PublicKey;
+  let bufferVaultPda: PublicKey;
 
   const projectId = `BCP-${Date.now()}`; // Make unique with timestamp
+
+  // Child PDAs are salted with the project's generation (earlier registrations of its ID)
+  const generationSeed = (generation = 0) => new anchor.BN(generation).toArrayLike(Buffer, "le", 4);
+  const verifierStakePda = (authority: PublicKey) =>
+    PublicKey.findProgramAddressSync([Buffer.from("verifier_stake"), authority.toBuffer()], program.programId)[0];
+  const registryIdRecordPda = (id: string) =>
+    PublicKey.findProgramAddressSync([Buffer.from("registry_id"), Buffer.from(id)], program.programId)[0];
+  const verificationRecordPda = (index: number, project: PublicKey = projectPda, generation = 0) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("verification_record"), project.toBuffer(), generationSeed(generation), new anchor.BN(index).toArrayLike(Buffer, "le", 4)],
+      program.programId
+    )[0];
+  const issuanceRecordPda = (index: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("issuance_record"), creditMint.toBuffer(), new anchor.BN(index).toArrayLike(Buffer, "le", 4)],
+      program.programId
+    )[0];
   const ipfsCid = "QmYwAPJzv5CZsnAzt8auVKRQm6VLw4Dy8YQANhBBfmGjw8";
 
+  // Fresh verifier with VERIFY_PROJECT permission, a blue carbon node and stake
+  const registerStakedVerifier = async (verifierType: object, stakeLamports = 1_500_000_000) => {
+    const authority = Keypair.generate();
+    const airdrop = await provider.connection.requestAirdrop(authority.publicKey, 3_000_000_000);
+    await provider.connection.confirmTransaction(airdrop);
+    const pda = (seed: string) =>
+      PublicKey.findProgramAddressSync([Buffer.from(seed), authority.publicKey.toBuffer()], program.programId)[0];
+
+    // VERIFY_PROJECT | VIEW_AUDIT_LOGS
+    await program.methods.assignRole(authority.publicKey, { validator: {} }, new anchor.BN(514))
+      .accounts({
+        userAccount: pda("user"),
+        registry: registryPda,
+        admin: projectOwner.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([projectOwner])
+      .rpc();
+    await program.methods.registerVerifier(
+      {
+        verifierType,
+        credentials: ["ACVA-BC-100"],
+        specializations: [{ blueCarbon: {} }],
+        credentialsExpireAt: new anchor.BN(0),
+      },
+      new anchor.BN(stakeLamports)
+    ).accounts({
+      verifier: pda("verifier"),
+      verifierStake: pda("verifier_stake"),
+      verifierAuthority: authority.publicKey,
+      systemProgram: SystemProgram.programId,
+    } as any).signers([authority]).rpc();
+
+    return { authority, userAccount: pda("user"), node: pda("verifier"), stake: pda("verifier_stake") };
+  };
+
+  // Registers an extra project at its own location, away from the main project's cells
+  const registerTestProject = async (id: string, latitude: number, longitude: number) => {
+    const [pda] = PublicKey.findProgramAddressSync([Buffer.from("project"), Buffer.from(id)], program.programId);
+    const projectData = {
+      projectId: id,
+      ipfsCid: ipfsCid,
+      carbonTonsEstimated: new anchor.BN(1000),
+      projectSector: { blueCarbon: {} },
+      location: {
+        latitude: microDegrees(latitude),
+        longitude: microDegrees(longitude),
+        polygonCoordinates: [],
+        countryCode: "IN",
+        regionName: "Sundarbans"
+      },
+      areaHectares: milli(50),
+      establishmentDate: new anchor.BN(Date.now() / 1000),
+      vintageYear: 2024,
+      pricePerTon: new anchor.BN(10_000_000),
+      cctsRegistryId: id,
+      complianceIdSignature: Buffer.from([]),
+    };
+    const attestation = complianceIdSignature(projectOwner, id, projectOwner.publicKey, projectData.location);
+    projectData.complianceIdSignature = attestation.signature;
+    const shards = h3ShardAccounts(program.programId, projectData.location);
+
+    await program.methods
+      .registerProject(projectData, new anchor.BN(100_000_000))
+      .accounts({
+        project: pda,
+        registryIdRecord: registryIdRecordPda(id),
+        registry: registryPda,
+        registryConfig: registryConfigPda,
+        projectOwner: projectOwner.publicKey,
+        userAccount: ownerUserAccount,
+        doubleCountingRegistry: doubleCountingRegistryPda,
+        instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .remainingAccounts(shards)
+      .preInstructions([attestation.ix])
+      .signers([projectOwner])
+      .rpc();
+
+    return { pda, shards };
+  };
+
   // Test constants
   const TOKEN_DECIMALS = 6;
   const INITIAL_MINT_AMOUNT = 600; // Match verified carbon tons
@@ -49,9 +242,14 @@ describe("blue-carbon-registry", () => {
       // Create a separate investor wallet
       investorWallet = Keypair.generate();
 
+      // Verifiers may not audit their own projects, so validation uses its own wallet
+      validator = Keypair.generate();
+      const validatorAirdrop = await provider.connection.requestAirdrop(validator.publicKey, 4000000000);
+      await provider.connection.confirmTransaction(validatorAirdrop);
+
       // Derive the project PDA
       [projectPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("project"), projectOwner.publicKey.toBuffer(), Buffer.from(projectId)],
+        [Buffer.from("project"), Buffer.from(projectId)],
         program.programId
       );
 
@@ -69,6 +267,11 @@ describe("blue-carbon-registry", () => {
 
       tokenMint = carbonTokenMintPda;
 
+      [registryConfigPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("registry_config")],
+        program.programId
+      );
+
       // Derive the double counting registry PDA
       [doubleCountingRegistryPda] = PublicKey.findProgramAddressSync(
         [Buffer.from("double_counting_registry")],
@@ -142,9 +345,34 @@ describe("blue-carbon-registry", () => {
     }
   });
 
+  it("Initializes the Registry Config", async () => {
+    try {
+      await program.account.registryConfig.fetch(registryConfigPda);
+      console.log("✅ Registry Config already exists");
+    } catch {
+      await program.methods
+        .initializeRegistryConfig()
+        .accounts({
+          registryConfig: registryConfigPda,
+          registry: registryPda,
+          carbonTokenMint: tokenMint,
+          admin: projectOwner.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([projectOwner])
+        .rpc();
+    }
+
+    const config = await program.account.registryConfig.fetch(registryConfigPda);
+    assert.equal(config.creditDecimals, TOKEN_DECIMALS);
+    assert.equal(config.h3Resolution, 8);
+    console.log("✅ Registry Config initialized");
+  });
+
   // New PDAs for User Accounts
   let ownerUserAccount: PublicKey;
   let investorUserAccount: PublicKey;
+  let validatorUserAccount: PublicKey;
 
   it("Assigns Roles for RBAC", async () => {
     // Derive User Account PDAs
@@ -183,6 +411,80 @@ describe("blue-carbon-registry", () => {
     }
   });
 
+  it("Registers a blue carbon validator", async () => {
+    [validatorUserAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from("user"), validator.publicKey.toBuffer()],
+      program.programId
+    );
+    const [verifierNodePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("verifier"), validator.publicKey.toBuffer()],
+      program.programId
+    );
+    const [verifierStakePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("verifier_stake"), validator.publicKey.toBuffer()],
+      program.programId
+    );
+
+    // VERIFY_PROJECT | VIEW_AUDIT_LOGS
+    const VALIDATOR_PERMISSIONS = new anchor.BN(514);
+    await program.methods.assignRole(
+      validator.publicKey,
+      { validator: {} },
+      VALIDATOR_PERMISSIONS
+    ).accounts({
+      userAccount: validatorUserAccount,
+      registry: registryPda,
+      admin: projectOwner.publicKey,
+      systemProgram: SystemProgram.programId,
+    } as any).signers([projectOwner]).rpc();
+
+    await program.methods.registerVerifier(
+      {
+        verifierType: { technicalAuditor: {} },
+        credentials: ["ACVA-BC-042"],
+        specializations: [{ blueCarbon: {} }],
+        credentialsExpireAt: new anchor.BN(0),
+      },
+      new anchor.BN(1_000_000_000) // Registry-wide minimum stake
+    ).accounts({
+      verifier: verifierNodePda,
+      verifierStake: verifierStakePda,
+      verifierAuthority: validator.publicKey,
+      systemProgram: SystemProgram.programId,
+    } as any).signers([validator]).rpc();
+
+    const node = await program.account.verificationNode.fetch(verifierNodePda);
+    assert.ok(node.isActive);
+    console.log("✅ Validator registered");
+  });
+
+  it("Suspends and reinstates the validator", async () => {
+    const [verifierNodePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("verifier"), validator.publicKey.toBuffer()],
+      program.programId
+    );
+    const accounts = {
+      verifier: verifierNodePda,
+      admin: projectOwner.publicKey,
+      adminAccount: ownerUserAccount,
+    } as any;
+
+    await program.methods
+      .suspendVerifier("Pending credential review", null)
+      .accounts(accounts)
+      .signers([projectOwner])
+      .rpc();
+    let node = await program.account.verificationNode.fetch(verifierNodePda);
+    assert.isFalse(node.isActive);
+    assert.equal(node.suspensionReason, "Pending credential review");
+
+    await program.methods.reinstateVerifier().accounts(accounts).signers([projectOwner]).rpc();
+    node = await program.account.verificationNode.fetch(verifierNodePda);
+    assert.isTrue(node.isActive);
+
+    console.log("✅ Validator suspended and reinstated");
+  });
+
   it("Registers a project successfully", async () => {
     const carbonTonsEstimated = new anchor.BN(1000);
     const verificationFee = new anchor.BN(100_000_000); // 0.1 SOL minimum
@@ -199,7 +501,7 @@ describe("blue-carbon-registry", () => {
         countryCode: "IN",
         regionName: "Sundarbans"
       },
-      areaHectares: 100,
+      areaHectares: milli(100),
       establishmentDate: new anchor.BN(Date.now() / 1000),
       vintageYear: 2024,
       pricePerTon: new anchor.BN(10_000_000),
@@ -207,49 +509,1106 @@ describe("blue-carbon-registry", () => {
       complianceIdSignature: Buffer.from([]),
     };
 
-    const tx = await program.methods
-      .registerProject(projectData, verificationFee)
+    // Project owner doubles as the government authority in tests
+    const attestation = complianceIdSignature(projectOwner, projectId, projectOwner.publicKey, projectData.location);
+    projectData.complianceIdSignature = attestation.signature;
+
+    const tx = await program.methods
+      .registerProject(projectData, verificationFee)
+      .accounts({
+        project: projectPda,
+        registryIdRecord: registryIdRecordPda(projectId),
+        registry: registryPda,
+        registryConfig: registryConfigPda,
+        projectOwner: projectOwner.publicKey,
+        userAccount: ownerUserAccount,
+        doubleCountingRegistry: doubleCountingRegistryPda,
+        instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .remainingAccounts(h3ShardAccounts(program.programId, projectData.location))
+      .preInstructions([attestation.ix])
+      .signers([projectOwner])
+      .rpc();
+
+    console.log("Project registration transaction signature:", tx);
+
+    const [shard] = h3ShardAccounts(program.programId, projectData.location);
+    const shardAccount = await program.account.doubleCountingShard.fetch(shard.pubkey);
+    assert.isAtLeast(shardAccount.occupiedCells, 1);
+
+    console.log("✅ Project registered with escrow funded");
+  });
+
+  it("Keeps the audit escrow locked until the audit deadline", async () => {
+    const project = await program.account.project.fetch(projectPda);
+    assert.ok(project.auditDeadline.gtn(0));
+
+    try {
+      await program.methods
+        .reclaimVerificationEscrow()
+        .accounts({
+          project: projectPda,
+          owner: projectOwner.publicKey,
+          verificationRound: null,
+          escrowVault: null,
+          escrowMint: null,
+          recipientTokenAccount: null,
+          tokenProgram: null,
+        } as any)
+        .signers([projectOwner])
+        .rpc();
+      assert.fail("Escrow should not be reclaimable before the deadline");
+    } catch (e) {
+      assert.include(e.toString(), "AuditDeadlineNotReached");
+      console.log("✅ Escrow reclaim blocked before deadline");
+    }
+  });
+
+  it("Rejects a project whose boundary overlaps registered H3 cells", async () => {
+    const overlappingId = `BCP-OVERLAP-${Date.now()}`;
+    const [overlappingPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("project"), Buffer.from(overlappingId)],
+      program.programId
+    );
+
+    // Centroid is far away, but the boundary covers the first project's cell at (0, 0)
+    const projectData = {
+      projectId: overlappingId,
+      ipfsCid: ipfsCid,
+      carbonTonsEstimated: new anchor.BN(500),
+      projectSector: { blueCarbon: {} },
+      location: {
+        latitude: microDegrees(0.02),
+        longitude: microDegrees(0.02),
+        polygonCoordinates: [[-0.01, -0.01], [-0.01, 0.03], [0.03, 0.03], [0.03, -0.01]]
+          .map(([lat, lng]) => [microDegrees(lat), microDegrees(lng)]),
+        countryCode: "IN",
+        regionName: "Sundarbans"
+      },
+      areaHectares: milli(1600),
+      establishmentDate: new anchor.BN(Date.now() / 1000),
+      vintageYear: 2024,
+      pricePerTon: new anchor.BN(10_000_000),
+      cctsRegistryId: overlappingId,
+      complianceIdSignature: Buffer.from([]),
+    };
+    const attestation = complianceIdSignature(projectOwner, overlappingId, projectOwner.publicKey, projectData.location);
+    projectData.complianceIdSignature = attestation.signature;
+
+    try {
+      await program.methods
+        .registerProject(projectData, new anchor.BN(100_000_000))
+        .accounts({
+          project: overlappingPda,
+          registryIdRecord: registryIdRecordPda(overlappingId),
+          registry: registryPda,
+          registryConfig: registryConfigPda,
+          projectOwner: projectOwner.publicKey,
+          userAccount: ownerUserAccount,
+          doubleCountingRegistry: doubleCountingRegistryPda,
+          instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .remainingAccounts(h3ShardAccounts(program.programId, projectData.location))
+        .preInstructions([attestation.ix])
+        .signers([projectOwner])
+        .rpc();
+      assert.fail("Overlapping boundary should be rejected");
+    } catch (e) {
+      assert.include(e.toString(), "H3CellsAlreadyRegistered");
+      console.log("✅ Overlapping boundary rejected");
+    }
+  });
+
+  it("Withdraws a project awaiting audit and releases its cells", async () => {
+    const withdrawnId = `BCP-WITHDRAW-${Date.now()}`;
+    const [withdrawnPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("project"), Buffer.from(withdrawnId)],
+      program.programId
+    );
+
+    const projectData = {
+      projectId: withdrawnId,
+      ipfsCid: ipfsCid,
+      carbonTonsEstimated: new anchor.BN(300),
+      projectSector: { blueCarbon: {} },
+      location: {
+        latitude: microDegrees(10),
+        longitude: microDegrees(10),
+        polygonCoordinates: [],
+        countryCode: "IN",
+        regionName: "Sundarbans"
+      },
+      areaHectares: milli(50),
+      establishmentDate: new anchor.BN(Date.now() / 1000),
+      vintageYear: 2024,
+      pricePerTon: new anchor.BN(10_000_000),
+      cctsRegistryId: withdrawnId,
+      complianceIdSignature: Buffer.from([]),
+    };
+    const attestation = complianceIdSignature(projectOwner, withdrawnId, projectOwner.publicKey, projectData.location);
+    projectData.complianceIdSignature = attestation.signature;
+    const shards = h3ShardAccounts(program.programId, projectData.location);
+
+    await program.methods
+      .registerProject(projectData, new anchor.BN(100_000_000))
+      .accounts({
+        project: withdrawnPda,
+        registryIdRecord: registryIdRecordPda(withdrawnId),
+        registry: registryPda,
+        registryConfig: registryConfigPda,
+        projectOwner: projectOwner.publicKey,
+        userAccount: ownerUserAccount,
+        doubleCountingRegistry: doubleCountingRegistryPda,
+        instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .remainingAccounts(shards)
+      .preInstructions([attestation.ix])
+      .signers([projectOwner])
+      .rpc();
+
+    await program.methods
+      .withdrawProject()
+      .accounts({
+        project: withdrawnPda,
+        registry: registryPda,
+        doubleCountingRegistry: doubleCountingRegistryPda,
+        owner: projectOwner.publicKey,
+        systemProgram: SystemProgram.programId,
+        escrowVault: null,
+        escrowMint: null,
+        ownerTokenAccount: null,
+        tokenProgram: null,
+      } as any)
+      .remainingAccounts(shards)
+      .signers([projectOwner])
+      .rpc();
+
+    assert.isNull(await provider.connection.getAccountInfo(withdrawnPda));
+    const shardAccount = await program.account.doubleCountingShard.fetch(shards[0].pubkey);
+    assert.equal(shardAccount.occupiedCells, 0);
+
+    console.log("✅ Project withdrawn, escrow refunded and cells released");
+  });
+
+  it("Re-registers a withdrawn ID without colliding with its earlier accounts", async () => {
+    const reusedId = `BCP-REUSE-${Date.now()}`;
+    const amend = (pda: PublicKey, generation: number, version: number) => {
+      const [projectVersionPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("project_version"), pda.toBuffer(), generationSeed(generation), new anchor.BN(version).toArrayLike(Buffer, "le", 4)],
+        program.programId
+      );
+      return program.methods
+        .amendProject({
+          ipfsCid: "QmT5NvUtoM5nWFfrQdVrFtvGfKFmG7AHE8P34isapyhCxX",
+          carbonTonsEstimated: null,
+          location: null,
+          areaHectares: null,
+          pricePerTon: null,
+          complianceIdSignature: Buffer.from([]),
+        })
+        .accounts({
+          project: pda,
+          projectVersion: projectVersionPda,
+          registry: registryPda,
+          registryConfig: registryConfigPda,
+          doubleCountingRegistry: doubleCountingRegistryPda,
+          instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          owner: projectOwner.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([projectOwner])
+        .rpc();
+    };
+
+    // First registration leaves a version record behind when withdrawn
+    const { pda, shards } = await registerTestProject(reusedId, 12, 12);
+    await amend(pda, 0, 2);
+    await program.methods
+      .withdrawProject()
+      .accounts({
+        project: pda,
+        registry: registryPda,
+        doubleCountingRegistry: doubleCountingRegistryPda,
+        owner: projectOwner.publicKey,
+        systemProgram: SystemProgram.programId,
+        escrowVault: null,
+        escrowMint: null,
+        ownerTokenAccount: null,
+        tokenProgram: null,
+      } as any)
+      .remainingAccounts(shards)
+      .signers([projectOwner])
+      .rpc();
+
+    // The same ID registers again as a new generation with fresh child accounts
+    await registerTestProject(reusedId, 12, 12);
+    const reregistered = await program.account.project.fetch(pda);
+    assert.equal(reregistered.generation, 1);
+    assert.equal(reregistered.version, 1);
+    assert.equal((await program.account.registryIdRecord.fetch(registryIdRecordPda(reusedId))).registrations, 2);
+
+    await amend(pda, 1, 2);
+    assert.equal((await program.account.project.fetch(pda)).version, 2);
+
+    const verifier = await registerStakedVerifier({ thirdPartyValidator: {} });
+    const recordPda = verificationRecordPda(0, pda, 1);
+    await program.methods
+      .verifyProject(new anchor.BN(1000))
+      .accounts({
+        project: pda,
+        registry: registryPda,
+        registryConfig: registryConfigPda,
+        admin: verifier.authority.publicKey,
+        adminAccount: verifier.userAccount,
+        verifierStake: verifier.stake,
+        verificationRecord: recordPda,
+      } as any)
+      .signers([verifier.authority])
+      .rpc();
+
+    const verified = await program.account.project.fetch(pda);
+    assert.equal(Object.keys(verified.verificationStatus)[0], "verified");
+    assert.ok((await program.account.verificationRecord.fetch(recordPda)).verifier.equals(verifier.authority.publicKey));
+
+    console.log("✅ Withdrawn ID re-registered, amended and verified");
+  });
+
+  it("Refunds the earlier escrow when reassigning the verifier", async () => {
+    const { pda } = await registerTestProject(`BCP-REASSIGN-${Date.now()}`, 16, 16);
+    const verifier = await registerStakedVerifier({ thirdPartyValidator: {} });
+    const fee = new anchor.BN(200_000_000);
+
+    await program.methods
+      .initializeVerification(fee)
+      .accounts({
+        project: pda,
+        registryConfig: registryConfigPda,
+        owner: projectOwner.publicKey,
+        verifier: verifier.authority.publicKey,
+        verifierStake: verifier.stake,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([projectOwner])
+      .rpc();
+
+    // The registration deposit went back to the owner; only the new fee is escrowed
+    const project = await program.account.project.fetch(pda);
+    assert.ok(project.verifier.equals(verifier.authority.publicKey));
+    assert.equal(project.verificationFeeLamports.toString(), fee.toString());
+    assert.equal(project.auditEscrowBalance.toString(), fee.toString());
+
+    console.log("✅ Verifier reassigned without stranding the earlier escrow");
+  });
+
+  it("Amends the project PDD and records a version", async () => {
+    const amendedCid = "QmT5NvUtoM5nWFfrQdVrFtvGfKFmG7AHE8P34isapyhCxX";
+    const [projectVersionPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("project_version"), projectPda.toBuffer(), generationSeed(), new anchor.BN(2).toArrayLike(Buffer, "le", 4)],
+      program.programId
+    );
+
+    await program.methods
+      .amendProject({
+        ipfsCid: amendedCid,
+        carbonTonsEstimated: null,
+        location: null,
+        areaHectares: null,
+        pricePerTon: null,
+        complianceIdSignature: Buffer.from([]),
+      })
+      .accounts({
+        project: projectPda,
+        projectVersion: projectVersionPda,
+        registry: registryPda,
+        registryConfig: registryConfigPda,
+        doubleCountingRegistry: doubleCountingRegistryPda,
+        instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        owner: projectOwner.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([projectOwner])
+      .rpc();
+
+    const projectAccount = await program.account.project.fetch(projectPda);
+    assert.equal(projectAccount.version, 2);
+    assert.equal(projectAccount.ipfsCid, amendedCid);
+
+    const history = await program.account.projectVersion.fetch(projectVersionPda);
+    assert.equal(history.oldIpfsCid, ipfsCid);
+    assert.equal(history.newIpfsCid, amendedCid);
+    assert.isFalse(history.locationChanged);
+
+    console.log("✅ Project amended to version 2");
+  });
+
+  it("Proposes and cancels a project ownership transfer", async () => {
+    await program.methods
+      .proposeProjectTransfer(investorWallet.publicKey)
+      .accounts({ project: projectPda, owner: projectOwner.publicKey } as any)
+      .signers([projectOwner])
+      .rpc();
+    let projectAccount = await program.account.project.fetch(projectPda);
+    assert.ok(projectAccount.pendingOwner.equals(investorWallet.publicKey));
+
+    await program.methods
+      .proposeProjectTransfer(null)
+      .accounts({ project: projectPda, owner: projectOwner.publicKey } as any)
+      .signers([projectOwner])
+      .rpc();
+    projectAccount = await program.account.project.fetch(projectPda);
+    assert.isNull(projectAccount.pendingOwner);
+    assert.ok(projectAccount.owner.equals(projectOwner.publicKey));
+
+    console.log("✅ Ownership transfer proposed and cancelled");
+  });
+
+  it("Hands a project to its accepted owner and locks out the previous one", async () => {
+    const { pda } = await registerTestProject(`BCP-TRANSFER-${Date.now()}`, 14, 14);
+    const newOwner = Keypair.generate();
+    const airdrop = await provider.connection.requestAirdrop(newOwner.publicKey, 1_000_000_000);
+    await provider.connection.confirmTransaction(airdrop);
+    const [newOwnerUserAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from("user"), newOwner.publicKey.toBuffer()],
+      program.programId
+    );
+    // REGISTER_PROJECT
+    await program.methods.assignRole(newOwner.publicKey, { user: {} }, new anchor.BN(1))
+      .accounts({
+        userAccount: newOwnerUserAccount,
+        registry: registryPda,
+        admin: projectOwner.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([projectOwner])
+      .rpc();
+
+    await program.methods
+      .proposeProjectTransfer(newOwner.publicKey)
+      .accounts({ project: pda, owner: projectOwner.publicKey } as any)
+      .signers([projectOwner])
+      .rpc();
+    const [ownershipRecordPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("ownership_record"), pda.toBuffer(), generationSeed(), new anchor.BN(0).toArrayLike(Buffer, "le", 4)],
+      program.programId
+    );
+    await program.methods
+      .acceptProjectTransfer()
+      .accounts({
+        project: pda,
+        ownershipRecord: ownershipRecordPda,
+        newOwner: newOwner.publicKey,
+        userAccount: newOwnerUserAccount,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([newOwner])
+      .rpc();
+
+    const transferred = await program.account.project.fetch(pda);
+    assert.ok(transferred.owner.equals(newOwner.publicKey));
+    assert.isNull(transferred.pendingOwner);
+    assert.equal(transferred.ownershipTransfers, 1);
+    const record = await program.account.projectOwnershipRecord.fetch(ownershipRecordPda);
+    assert.ok(record.project.equals(pda));
+    assert.equal(record.transferIndex, 0);
+    assert.ok(record.previousOwner.equals(projectOwner.publicKey));
+    assert.ok(record.newOwner.equals(newOwner.publicKey));
+
+    const [projectVersionPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("project_version"), pda.toBuffer(), generationSeed(), new anchor.BN(2).toArrayLike(Buffer, "le", 4)],
+      program.programId
+    );
+    const amendAs = (owner: Keypair) =>
+      program.methods
+        .amendProject({
+          ipfsCid: "QmT5NvUtoM5nWFfrQdVrFtvGfKFmG7AHE8P34isapyhCxX",
+          carbonTonsEstimated: null,
+          location: null,
+          areaHectares: null,
+          pricePerTon: null,
+          complianceIdSignature: Buffer.from([]),
+        })
+        .accounts({
+          project: pda,
+          projectVersion: projectVersionPda,
+          registry: registryPda,
+          registryConfig: registryConfigPda,
+          doubleCountingRegistry: doubleCountingRegistryPda,
+          instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          owner: owner.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([owner])
+        .rpc();
+
+    // The previous owner no longer passes has_one = owner
+    try {
+      await amendAs(projectOwner);
+      assert.fail("Previous owner should not amend a transferred project");
+    } catch (e) {
+      assert.include(e.toString(), "ConstraintHasOne");
+    }
+    try {
+      await program.methods
+        .proposeProjectTransfer(projectOwner.publicKey)
+        .accounts({ project: pda, owner: projectOwner.publicKey } as any)
+        .signers([projectOwner])
+        .rpc();
+      assert.fail("Previous owner should not propose a transfer");
+    } catch (e) {
+      assert.include(e.toString(), "ConstraintHasOne");
+    }
+
+    await amendAs(newOwner);
+    assert.equal((await program.account.project.fetch(pda)).version, 2);
+
+    console.log("✅ Ownership transferred; new owner amends, previous owner rejected");
+  });
+
+  it("Attaches blue carbon sector data to the project", async () => {
+    const [sectorDataPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("sector_data"), projectPda.toBuffer(), generationSeed()],
+      program.programId
+    );
+
+    await program.methods
+      .initializeSectorData({
+        natureBased: {
+          0: {
+            speciesComposition: ["Rhizophora mucronata", "Avicennia marina"],
+            aboveGroundBiomass: milli(120.5),
+            belowGroundBiomass: milli(45.2),
+            soilCarbon030Cm: milli(80),
+            soilCarbon30100Cm: milli(150),
+            permanenceGuaranteeYears: 40,
+          },
+        },
+      })
+      .accounts({
+        project: projectPda,
+        sectorData: sectorDataPda,
+        owner: projectOwner.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([projectOwner])
+      .rpc();
+
+    const sectorData = await program.account.projectSectorData.fetch(sectorDataPda);
+    assert.ok(sectorData.project.equals(projectPda));
+    assert.equal(sectorData.details.natureBased[0].permanenceGuaranteeYears, 40);
+
+    console.log("✅ Sector data attached");
+  });
+
+  it("Awards an audit job to a bidding validator", async () => {
+    const project = await program.account.project.fetch(projectPda);
+    const [auditJobPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("audit_job"), projectPda.toBuffer(), generationSeed(), new anchor.BN(project.auditJobs).toArrayLike(Buffer, "le", 4)],
+      program.programId
+    );
+    const [auditBidPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("audit_bid"), auditJobPda.toBuffer(), validator.publicKey.toBuffer()],
+      program.programId
+    );
+    const price = new anchor.BN(100_000_000);
+
+    await program.methods
+      .postAuditJob(new anchor.BN(200_000_000))
+      .accounts({
+        project: projectPda,
+        auditJob: auditJobPda,
+        registryConfig: registryConfigPda,
+        owner: projectOwner.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([projectOwner])
+      .rpc();
+
+    // Bidders need the registry-wide minimum stake even though the project asks for none
+    const unstaked = await registerStakedVerifier({ thirdPartyValidator: {} }, 0);
+    try {
+      await program.methods
+        .submitAuditBid(price, 30)
+        .accounts({
+          project: projectPda,
+          auditJob: auditJobPda,
+          auditBid: PublicKey.findProgramAddressSync(
+            [Buffer.from("audit_bid"), auditJobPda.toBuffer(), unstaked.authority.publicKey.toBuffer()],
+            program.programId
+          )[0],
+          registryConfig: registryConfigPda,
+          verifierAuthority: unstaked.authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([unstaked.authority])
+        .rpc();
+      assert.fail("Unstaked verifier should not bid");
+    } catch (e) {
+      assert.include(e.toString(), "InsufficientVerifierStake");
+    }
+
+    await program.methods
+      .submitAuditBid(price, 30)
+      .accounts({
+        project: projectPda,
+        auditJob: auditJobPda,
+        auditBid: auditBidPda,
+        registryConfig: registryConfigPda,
+        verifierAuthority: validator.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([validator])
+      .rpc();
+
+    await program.methods
+      .acceptAuditBid()
+      .accounts({
+        project: projectPda,
+        auditJob: auditJobPda,
+        auditBid: auditBidPda,
+        verifier: PublicKey.findProgramAddressSync([Buffer.from("verifier"), validator.publicKey.toBuffer()], program.programId)[0],
+        verifierStake: verifierStakePda(validator.publicKey),
+        registryConfig: registryConfigPda,
+        owner: projectOwner.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([projectOwner])
+      .rpc();
+
+    const awarded = await program.account.project.fetch(projectPda);
+    assert.ok(awarded.verifier.equals(validator.publicKey));
+    assert.equal(awarded.verificationFeeLamports.toString(), price.toString());
+    const job = await program.account.auditJob.fetch(auditJobPda);
+    assert.equal(Object.keys(job.status)[0], "awarded");
+
+    console.log("✅ Audit job awarded");
+  });
+
+  it("Lets only the assigned verifier reject a project", async () => {
+    const other = await registerStakedVerifier({ thirdPartyValidator: {} });
+    const project = await program.account.project.fetch(projectPda);
+    assert.ok(project.verifier.equals(validator.publicKey));
+
+    try {
+      await program.methods
+        .rejectProject("Insufficient monitoring evidence")
+        .accounts({
+          project: projectPda,
+          registry: registryPda,
+          registryConfig: registryConfigPda,
+          admin: other.authority.publicKey,
+          adminAccount: other.userAccount,
+          verifierNode: other.node,
+          verifierStake: other.stake,
+          verificationRecord: verificationRecordPda(project.verificationRecords),
+          escrowVault: null,
+          escrowMint: null,
+          recipientTokenAccount: null,
+          tokenProgram: null,
+        } as any)
+        .signers([other.authority])
+        .rpc();
+      assert.fail("Only the assigned verifier may reject");
+    } catch (e) {
+      assert.include(e.toString(), "UnauthorizedVerifier");
+    }
+
+    console.log("✅ Unassigned verifier cannot reject");
+  });
+
+  it("Verifies Project (Validator)", async () => {
+    // Escrow is already funded at registration
+    // Just verify and release escrow to verifier
+
+    const projectAccountBefore = await program.account.project.fetch(projectPda);
+    console.log("Escrow balance before:", projectAccountBefore.auditEscrowBalance.toString());
+
+    const verifiedCarbonTons = new anchor.BN(1000);
+    const recordPda = verificationRecordPda(projectAccountBefore.verificationRecords);
+
+    const tx = await program.methods
+      .verifyProject(verifiedCarbonTons)
+      .accounts({
+        project: projectPda,
+        registry: registryPda,
+        registryConfig: registryConfigPda,
+        admin: validator.publicKey,
+        adminAccount: validatorUserAccount,
+        verifierStake: verifierStakePda(validator.publicKey),
+        verificationRecord: recordPda,
+      } as any)
+      .signers([validator])
+      .rpc();
+
+    console.log("Project verification transaction signature:", tx);
+
+    const projectAccountAfter = await program.account.project.fetch(projectPda);
+    assert.equal(Object.keys(projectAccountAfter.verificationStatus)[0], "verified");
+
+    const record = await program.account.verificationRecord.fetch(recordPda);
+    assert.ok(record.verifier.equals(validator.publicKey));
+    assert.equal(Object.keys(record.decision)[0], "verified");
+    assert.equal(record.carbonTons.toString(), verifiedCarbonTons.toString());
+    assert.equal(record.feePaid.toNumber(), 0); // Escrowed until the challenge window closes
+
+    console.log("✅ Project verified successfully");
+  });
+
+  it("Rejects an illegal status transition", async () => {
+    const project = await program.account.project.fetch(projectPda);
+    try {
+      await program.methods
+        .verifyProject(new anchor.BN(1000))
+        .accounts({
+          project: projectPda,
+          registry: registryPda,
+          registryConfig: registryConfigPda,
+          admin: validator.publicKey,
+          adminAccount: validatorUserAccount,
+          verifierStake: verifierStakePda(validator.publicKey),
+          verificationRecord: verificationRecordPda(project.verificationRecords),
+        } as any)
+        .signers([validator])
+        .rpc();
+      assert.fail("Verified -> Verified should not be allowed");
+    } catch (e) {
+      assert.include(e.toString(), "InvalidStatusTransition");
+      console.log("✅ Illegal transition rejected");
+    }
+  });
+
+  it("Does not expire a verification that is still valid", async () => {
+    const project = await program.account.project.fetch(projectPda);
+    assert.ok(project.verificationExpiresAt.gtn(0));
+
+    try {
+      await program.methods
+        .expireVerification()
+        .accounts({ project: projectPda, cranker: projectOwner.publicKey } as any)
+        .signers([projectOwner])
+        .rpc();
+      assert.fail("Expiry crank should fail before the validity period ends");
+    } catch (e) {
+      assert.include(e.toString(), "VerificationNotExpired");
+      console.log("✅ Valid verification not expired");
+    }
+  });
+
+  it("Rejects tonnage amendments after verification", async () => {
+    const project = await program.account.project.fetch(projectPda);
+    const [projectVersionPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("project_version"), projectPda.toBuffer(), generationSeed(), new anchor.BN(project.version + 1).toArrayLike(Buffer, "le", 4)],
+      program.programId
+    );
+
+    try {
+      await program.methods
+        .amendProject({
+          ipfsCid: null,
+          carbonTonsEstimated: new anchor.BN(project.carbonTonsEstimated.toNumber() * 2),
+          location: null,
+          areaHectares: null,
+          pricePerTon: null,
+          complianceIdSignature: Buffer.from([]),
+        })
+        .accounts({
+          project: projectPda,
+          projectVersion: projectVersionPda,
+          registry: registryPda,
+          registryConfig: registryConfigPda,
+          doubleCountingRegistry: doubleCountingRegistryPda,
+          instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          owner: projectOwner.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([projectOwner])
+        .rpc();
+      assert.fail("Verified tonnage should not be amendable");
+    } catch (e) {
+      assert.include(e.toString(), "VerifiedTonnageLocked");
+    }
+
+    const after = await program.account.project.fetch(projectPda);
+    assert.equal(after.carbonTonsEstimated.toString(), project.carbonTonsEstimated.toString());
+    console.log("✅ Verified tonnage kept until re-verification");
+  });
+
+  it("Rejects a dispute bond below the configured minimum", async () => {
+    const project = await program.account.project.fetch(projectPda);
+    const [disputePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("dispute"), projectPda.toBuffer(), generationSeed(), new anchor.BN(project.disputes).toArrayLike(Buffer, "le", 4)],
+      program.programId
+    );
+
+    try {
+      await program.methods
+        .openDispute(new anchor.BN(1), "QmDisputeEvidence")
+        .accounts({
+          project: projectPda,
+          dispute: disputePda,
+          registryConfig: registryConfigPda,
+          challenger: projectOwner.publicKey,
+          challengerAccount: ownerUserAccount,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([projectOwner])
+        .rpc();
+      assert.fail("Dispute with a 1 lamport bond should fail");
+    } catch (e) {
+      assert.include(e.toString(), "InsufficientDisputeBond");
+      console.log("✅ Under-bonded dispute rejected");
+    }
+  });
+
+  // ===================================
+  // Verification Round Tests
+  // ===================================
+
+  let roundProjectPda: PublicKey;
+  let roundPda: PublicKey;
+  let roundVerifiers: Awaited<ReturnType<typeof registerStakedVerifier>>[];
+
+  const attestRound = async (
+    verifier: Awaited<ReturnType<typeof registerStakedVerifier>>,
+    tons: number,
+    qualityRating: number
+  ) => {
+    const project = await program.account.project.fetch(roundProjectPda);
+    const [verifierAudit] = PublicKey.findProgramAddressSync(
+      [Buffer.from("verifier_audit"), verifier.authority.publicKey.toBuffer(), projectOwner.publicKey.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .multiPartyVerifyProject(new anchor.BN(tons), qualityRating, ipfsCid)
+      .accounts({
+        project: roundProjectPda,
+        registryConfig: registryConfigPda,
+        verificationRound: roundPda,
+        verifier: verifier.node,
+        verifierStake: verifier.stake,
+        verifierAccount: verifier.userAccount,
+        verifierAudit,
+        verificationRecord: verificationRecordPda(project.verificationRecords, roundProjectPda),
+        verifierAuthority: verifier.authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([verifier.authority])
+      .rpc();
+  };
+
+  const finalizeRound = async () => {
+    const project = await program.account.project.fetch(roundProjectPda);
+    await program.methods
+      .finalizeVerificationRound()
+      .accounts({
+        project: roundProjectPda,
+        verificationRound: roundPda,
+        registryConfig: registryConfigPda,
+        verificationRecord: verificationRecordPda(project.verificationRecords, roundProjectPda),
+        authority: projectOwner.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([projectOwner])
+      .rpc();
+  };
+
+  it("Rejects finalizing a verification round below quorum", async () => {
+    ({ pda: roundProjectPda } = await registerTestProject(`BCP-ROUND-${Date.now()}`, 20, 20));
+    roundVerifiers = [
+      await registerStakedVerifier({ technicalAuditor: {} }),
+      await registerStakedVerifier({ scientificInstitution: {} }),
+      await registerStakedVerifier({ thirdPartyValidator: {} }),
+    ];
+
+    const project = await program.account.project.fetch(roundProjectPda);
+    [roundPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("verification_round"), roundProjectPda.toBuffer(), generationSeed(), new anchor.BN(project.verificationRounds).toArrayLike(Buffer, "le", 4)],
+      program.programId
+    );
+
+    // Quorum of 2, a technical auditor among the inliers, outliers beyond 20% of the median
+    await program.methods
+      .openVerificationRound(2, [{ technicalAuditor: {} }], 2_000, [])
+      .accounts({
+        project: roundProjectPda,
+        registry: registryPda,
+        verificationRound: roundPda,
+        registryConfig: registryConfigPda,
+        admin: projectOwner.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([projectOwner])
+      .rpc();
+
+    await attestRound(roundVerifiers[0], 1000, 4);
+
+    try {
+      await finalizeRound();
+      assert.fail("A single attestation should not reach quorum");
+    } catch (e) {
+      assert.include(e.toString(), "QuorumNotReached");
+    }
+
+    const round = await program.account.verificationRound.fetch(roundPda);
+    assert.isFalse(round.finalized);
+    assert.equal(round.attestations.length, 1);
+    console.log("✅ Round below quorum not finalized");
+
+    // The attesting verifier is owed the escrow, so the owner cannot pull it back
+    try {
+      await program.methods
+        .reclaimVerificationEscrow()
+        .accounts({
+          project: roundProjectPda,
+          owner: projectOwner.publicKey,
+          verificationRound: roundPda,
+          escrowVault: null,
+          escrowMint: null,
+          recipientTokenAccount: null,
+          tokenProgram: null,
+        } as any)
+        .signers([projectOwner])
+        .rpc();
+      assert.fail("Escrow should not be reclaimable with pending attestations");
+    } catch (e) {
+      assert.include(e.toString(), "VerificationInProgress");
+      console.log("✅ Escrow reclaim blocked by pending attestations");
+    }
+  });
+
+  it("Excludes outlier attestations and finalizes at the inlier median", async () => {
+    await attestRound(roundVerifiers[1], 1100, 5);
+    await attestRound(roundVerifiers[2], 5000, 1);
+
+    await finalizeRound();
+
+    // Median of all is 1100; 5000 is beyond 20% of it, 1000 is not
+    const round = await program.account.verificationRound.fetch(roundPda);
+    assert.isTrue(round.finalized);
+    assert.deepEqual(round.attestations.map((a) => a.isOutlier), [false, false, true]);
+    assert.equal(round.verifiedCarbonTons.toNumber(), 1050);
+    assert.equal(round.qualityRating, 4);
+
+    const project = await program.account.project.fetch(roundProjectPda);
+    assert.equal(Object.keys(project.verificationStatus)[0], "verified");
+    assert.equal(project.carbonTonsEstimated.toNumber(), 1050);
+    assert.equal(project.qualityRating, 4);
+    assert.equal(project.auditDeadline.toNumber(), 0);
+
+    const record = await program.account.verificationRecord.fetch(
+      verificationRecordPda(project.verificationRecords - 1, roundProjectPda)
+    );
+    assert.equal(Object.keys(record.decision)[0], "roundFinalized");
+    console.log("✅ Round finalized at the inlier median");
+  });
+
+  // ===================================
+  // Verifier Stake Tests
+  // ===================================
+
+  let staker: Awaited<ReturnType<typeof registerStakedVerifier>>;
+
+  it("Deposits and unbonds verifier stake, blocking early withdrawal", async () => {
+    staker = await registerStakedVerifier({ technicalAuditor: {} }, 1_000_000_000);
+    const accounts = {
+      verifierStake: staker.stake,
+      registryConfig: registryConfigPda,
+      authority: staker.authority.publicKey,
+      systemProgram: SystemProgram.programId,
+    } as any;
+
+    await program.methods.depositStake(new anchor.BN(500_000_000)).accounts(accounts).signers([staker.authority]).rpc();
+    let stake = await program.account.verifierStake.fetch(staker.stake);
+    assert.equal(stake.staked.toNumber(), 1_500_000_000);
+
+    await program.methods.requestStakeUnbonding(new anchor.BN(500_000_000)).accounts(accounts).signers([staker.authority]).rpc();
+    stake = await program.account.verifierStake.fetch(staker.stake);
+    assert.equal(stake.staked.toNumber(), 1_000_000_000);
+    assert.equal(stake.unbonding.toNumber(), 500_000_000);
+    assert.isAbove(stake.unbondingEndsAt.toNumber(), Math.floor(Date.now() / 1000));
+
+    try {
+      await program.methods.withdrawUnbondedStake().accounts(accounts).signers([staker.authority]).rpc();
+      assert.fail("Stake should not be withdrawable while unbonding");
+    } catch (e) {
+      assert.include(e.toString(), "StakeStillUnbonding");
+    }
+
+    stake = await program.account.verifierStake.fetch(staker.stake);
+    assert.equal(stake.unbonding.toNumber(), 500_000_000);
+    console.log("✅ Stake deposited and unbonding, early withdrawal blocked");
+  });
+
+  it("Slashes verifier stake through a governance proposal", async () => {
+    const [multisigPda] = PublicKey.findProgramAddressSync([Buffer.from("multisig")], program.programId);
+    try {
+      await program.account.multiSigConfig.fetch(multisigPda);
+    } catch {
+      await program.methods
+        .initializeMultisig([projectOwner.publicKey], 1)
+        .accounts({
+          multisigConfig: multisigPda,
+          registry: registryPda,
+          admin: projectOwner.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([projectOwner])
+        .rpc();
+    }
+
+    const proposalId = new anchor.BN(Date.now());
+    const [proposalPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    // VerifierSlash { amount, reputation_penalty }: 1.2 SOL takes all active stake and 0.2 SOL unbonding
+    const slash = Buffer.concat([
+      new anchor.BN(1_200_000_000).toArrayLike(Buffer, "le", 8),
+      new anchor.BN(10).toArrayLike(Buffer, "le", 8),
+    ]);
+
+    await program.methods
+      .createProposal(proposalId, { slashVerifier: {} }, staker.authority.publicKey, slash, new anchor.BN(3600))
       .accounts({
-        project: projectPda,
-        registry: registryPda,
-        projectOwner: projectOwner.publicKey,
-        userAccount: ownerUserAccount,
-        doubleCountingRegistry: doubleCountingRegistryPda,
+        proposal: proposalPda,
+        multisigConfig: multisigPda,
+        proposerAccount: ownerUserAccount,
+        proposer: projectOwner.publicKey,
         systemProgram: SystemProgram.programId,
       } as any)
       .signers([projectOwner])
       .rpc();
+    await program.methods
+      .approveProposal()
+      .accounts({
+        proposal: proposalPda,
+        multisigConfig: multisigPda,
+        approverAccount: ownerUserAccount,
+        approver: projectOwner.publicKey,
+      } as any)
+      .signers([projectOwner])
+      .rpc();
 
-    console.log("Project registration transaction signature:", tx);
-    console.log("✅ Project registered with escrow funded");
-  });
+    const registryBalance = await provider.connection.getBalance(registryPda);
+    await program.methods
+      .executeProposal()
+      .accounts({
+        proposal: proposalPda,
+        multisigConfig: multisigPda,
+        executorAccount: ownerUserAccount,
+        executor: projectOwner.publicKey,
+        registryConfig: null,
+        doubleCountingRegistry: null,
+        verifierStake: staker.stake,
+        verifier: staker.node,
+        registry: registryPda,
+        dispute: null,
+        project: null,
+        challenger: null,
+        projectOwner: null,
+        escrowVault: null,
+        escrowMint: null,
+        ownerTokenAccount: null,
+        tokenProgram: null,
+      } as any)
+      .signers([projectOwner])
+      .rpc();
 
-  it("Verifies Project (Validator)", async () => {
-    // Escrow is already funded at registration
-    // Just verify and release escrow to verifier
+    const stake = await program.account.verifierStake.fetch(staker.stake);
+    assert.equal(stake.staked.toNumber(), 0);
+    assert.equal(stake.unbonding.toNumber(), 300_000_000);
+    assert.equal(stake.totalSlashed.toNumber(), 1_200_000_000);
+    assert.equal(await provider.connection.getBalance(registryPda), registryBalance + 1_200_000_000);
 
-    const projectAccountBefore = await program.account.project.fetch(projectPda);
-    console.log("Escrow balance before:", projectAccountBefore.auditEscrowBalance.toString());
+    const node = await program.account.verificationNode.fetch(staker.node);
+    assert.equal(node.reputationScore.toNumber(), 90);
+    console.log("✅ Verifier slashed by governance");
+  });
 
-    const verifiedCarbonTons = new anchor.BN(1000);
+  it("Refunds the escrowed fee to the owner when a dispute is overturned", async () => {
+    const [multisigPda] = PublicKey.findProgramAddressSync([Buffer.from("multisig")], program.programId);
+    const project = await program.account.project.fetch(roundProjectPda);
+    const fee = project.verificationFeeLamports.toNumber();
+    assert.isAbove(fee, 0);
+    const [disputePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("dispute"), roundProjectPda.toBuffer(), generationSeed(), new anchor.BN(project.disputes).toArrayLike(Buffer, "le", 4)],
+      program.programId
+    );
 
-    const tx = await program.methods
-      .verifyProject(verifiedCarbonTons)
+    await program.methods
+      .openDispute(new anchor.BN(500_000_000), "QmOverturnEvidence")
       .accounts({
-        project: projectPda,
-        registry: registryPda,
-        admin: projectOwner.publicKey,
-        adminAccount: ownerUserAccount,
+        project: roundProjectPda,
+        dispute: disputePda,
+        registryConfig: registryConfigPda,
+        challenger: projectOwner.publicKey,
+        challengerAccount: ownerUserAccount,
+        systemProgram: SystemProgram.programId,
       } as any)
       .signers([projectOwner])
       .rpc();
 
-    console.log("Project verification transaction signature:", tx);
+    const proposalId = new anchor.BN(Date.now());
+    const [proposalPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    // DisputeResolution { uphold: false }
+    await program.methods
+      .createProposal(proposalId, { resolveDispute: {} }, disputePda, Buffer.from([0]), new anchor.BN(3600))
+      .accounts({
+        proposal: proposalPda,
+        multisigConfig: multisigPda,
+        proposerAccount: ownerUserAccount,
+        proposer: projectOwner.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([projectOwner])
+      .rpc();
+    await program.methods
+      .approveProposal()
+      .accounts({
+        proposal: proposalPda,
+        multisigConfig: multisigPda,
+        approverAccount: ownerUserAccount,
+        approver: projectOwner.publicKey,
+      } as any)
+      .signers([projectOwner])
+      .rpc();
 
-    const projectAccountAfter = await program.account.project.fetch(projectPda);
-    assert.equal(Object.keys(projectAccountAfter.verificationStatus)[0], "verified");
+    const projectBalance = await provider.connection.getBalance(roundProjectPda);
+    await program.methods
+      .executeProposal()
+      .accounts({
+        proposal: proposalPda,
+        multisigConfig: multisigPda,
+        executorAccount: ownerUserAccount,
+        executor: projectOwner.publicKey,
+        registryConfig: null,
+        doubleCountingRegistry: null,
+        verifierStake: null,
+        verifier: null,
+        registry: registryPda,
+        dispute: disputePda,
+        project: roundProjectPda,
+        challenger: projectOwner.publicKey,
+        projectOwner: projectOwner.publicKey,
+        escrowVault: null,
+        escrowMint: null,
+        ownerTokenAccount: null,
+        tokenProgram: null,
+      } as any)
+      .signers([projectOwner])
+      .rpc();
 
-    console.log("✅ Project verified successfully");
+    const after = await program.account.project.fetch(roundProjectPda);
+    assert.deepEqual(after.verificationStatus, { rejected: {} });
+    assert.equal(after.verificationFeeLamports.toNumber(), 0);
+    assert.equal(after.auditEscrowBalance.toNumber(), project.auditEscrowBalance.toNumber() - fee);
+    assert.equal(await provider.connection.getBalance(roundProjectPda), projectBalance - fee);
+    console.log("✅ Overturned dispute refunded the escrowed fee");
   });
 
   it("Approves project compliance (Government)", async () => {
@@ -272,16 +1631,161 @@ describe("blue-carbon-registry", () => {
     console.log("✅ Compliance approved successfully");
   });
 
+  it("Opens and verifies an issuance batch", async () => {
+    const project = await program.account.project.fetch(projectPda);
+    [issuanceBatchPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("issuance_batch"), projectPda.toBuffer(), generationSeed(), new anchor.BN(project.issuanceBatches).toArrayLike(Buffer, "le", 4)],
+      program.programId
+    );
+    const now = Math.floor(Date.now() / 1000);
+    const openBatch = (vintage: number) =>
+      program.methods
+        .openIssuanceBatch(
+          new anchor.BN(now - 30 * 24 * 60 * 60),
+          new anchor.BN(now - 24 * 60 * 60),
+          vintage,
+          new anchor.BN(1000),
+          ipfsCid
+        )
+        .accounts({
+          project: projectPda,
+          issuanceBatch: issuanceBatchPda,
+          owner: projectOwner.publicKey,
+        } as any)
+        .signers([projectOwner])
+        .rpc();
+
+    try {
+      await openBatch(new Date().getFullYear() + 1);
+      assert.fail("Future vintages should be rejected");
+    } catch (e) {
+      assert.include(e.toString(), "InvalidVintageYear");
+    }
+    await openBatch(new Date().getFullYear());
+
+    const recordPda = verificationRecordPda(project.verificationRecords);
+    const verifyBatch = (verifier: Keypair, userAccount: PublicKey) =>
+      program.methods
+        .verifyIssuanceBatch(new anchor.BN(800))
+        .accounts({
+          project: projectPda,
+          issuanceBatch: issuanceBatchPda,
+          registryConfig: registryConfigPda,
+          verifierAccount: userAccount,
+          verifierStake: verifierStakePda(verifier.publicKey),
+          verificationRecord: recordPda,
+          verifierAuthority: verifier.publicKey,
+        } as any)
+        .signers([verifier])
+        .rpc();
+
+    // The validator audited this owner when verifying the project, so the cooldown applies
+    try {
+      await verifyBatch(validator, validatorUserAccount);
+      assert.fail("Audit cooldown should apply to batch verification");
+    } catch (e) {
+      assert.include(e.toString(), "AuditCooldownActive");
+    }
+    const batchVerifier = await registerStakedVerifier({ thirdPartyValidator: {} });
+    await verifyBatch(batchVerifier.authority, batchVerifier.userAccount);
+
+    const batch = await program.account.issuanceBatch.fetch(issuanceBatchPda);
+    assert.equal(Object.keys(batch.status)[0], "verified");
+    assert.equal(batch.verifiedTons.toNumber(), 800);
+    assert.equal(batch.vintageYear, new Date().getFullYear());
+
+    const record = await program.account.verificationRecord.fetch(recordPda);
+    assert.equal(Object.keys(record.decision)[0], "batchVerified");
+
+    console.log("✅ Issuance batch verified");
+  });
+
+  it("Creates the project's vintage credit mint", async () => {
+    const vintage = new Date().getFullYear();
+    [creditMint] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("credit_mint"),
+        projectPda.toBuffer(),
+        generationSeed(),
+        new anchor.BN(vintage).toArrayLike(Buffer, "le", 2),
+        Buffer.from([1]), // export authorized
+      ],
+      program.programId
+    );
+    [creditMintInfoPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("credit_mint_info"), creditMint.toBuffer()],
+      program.programId
+    );
+    [bufferVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("buffer_vault"), creditMint.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .createCreditMint(vintage, "VM0033", true)
+      .accounts({
+        project: projectPda,
+        registry: registryPda,
+        registryConfig: registryConfigPda,
+        creditMint,
+        creditMintInfo: creditMintInfoPda,
+        owner: projectOwner.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([projectOwner])
+      .rpc();
+
+    const info = await program.account.creditMintInfo.fetch(creditMintInfoPda);
+    assert.ok(info.project.equals(projectPda));
+    assert.equal(info.vintageYear, vintage);
+    assert.isTrue(info.exportAuthorized);
+
+    const mint = await getMint(provider.connection, creditMint, undefined, TOKEN_2022_PROGRAM_ID);
+    assert.ok(mint.mintAuthority.equals(registryPda));
+
+    const metadata = await getTokenMetadata(provider.connection, creditMint, undefined, TOKEN_2022_PROGRAM_ID);
+    assert.equal(metadata.symbol, "ACCORD");
+    const fields = Object.fromEntries(metadata.additionalMetadata);
+    assert.equal(fields.project_id, projectId);
+    assert.equal(fields.vintage, vintage.toString());
+    assert.equal(fields.methodology, "VM0033");
+    assert.equal(fields.pdd_uri, `ipfs://${ipfsCid}`);
+    assert.equal(fields.export_authorized, "true");
+
+    console.log("✅ Vintage credit mint created:", creditMint.toString());
+  });
+
+  it("Updates credit mint metadata when compliance changes", async () => {
+    await program.methods
+      .updateCreditMintMetadata("loa_issued", "true")
+      .accounts({
+        registry: registryPda,
+        creditMintInfo: creditMintInfoPda,
+        creditMint,
+        admin: projectOwner.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([projectOwner])
+      .rpc();
+
+    const metadata = await getTokenMetadata(provider.connection, creditMint, undefined, TOKEN_2022_PROGRAM_ID);
+    const fields = Object.fromEntries(metadata.additionalMetadata);
+    assert.equal(fields.loa_issued, "true");
+    console.log("✅ Credit mint metadata updated");
+  });
+
   it("Mints credits successfully", async () => {
     // Use the carbon token mint from the registry (already created)
-    console.log("Token mint:", tokenMint.toString());
+    console.log("Token mint:", creditMint.toString());
 
     // Create token account for the project owner
     projectTokenAccount = (
       await getOrCreateAssociatedTokenAccount(
         provider.connection,
         projectOwner,
-        tokenMint,
+        creditMint,
         projectOwner.publicKey,
         false,
         undefined,
@@ -294,17 +1798,27 @@ describe("blue-carbon-registry", () => {
 
     const amountToMint = new anchor.BN(INITIAL_MINT_AMOUNT * (10 ** TOKEN_DECIMALS)); // 1000 tokens with 6 decimals
 
+    const mintInfo = await program.account.creditMintInfo.fetch(creditMintInfoPda);
+    const recordPda = issuanceRecordPda(mintInfo.issuances);
+
     const tx = await program.methods
       .mintVerifiedCredits(amountToMint)
       .accounts({
         project: projectPda,
+        issuanceBatch: issuanceBatchPda,
         registry: registryPda,
-        carbonTokenMint: tokenMint,
+        registryConfig: registryConfigPda,
+        carbonTokenMint: creditMint,
+        creditMintInfo: creditMintInfoPda,
+        issuanceRecord: recordPda,
+        bufferVault: bufferVaultPda,
         recipientTokenAccount: projectTokenAccount,
+        recipientJurisdiction: null,
         owner: projectOwner.publicKey,
         recipient: projectOwner.publicKey,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
       } as any)
       .signers([projectOwner])
       .rpc();
@@ -320,8 +1834,25 @@ describe("blue-carbon-registry", () => {
       TOKEN_2022_PROGRAM_ID
     );
 
-    assert.equal(projectAccount.creditsIssued.toString(), amountToMint.toString());
-    assert.equal(projectAccount.tokensMinted.toString(), amountToMint.toString());
+    // BlueCarbon withholds the default 20% buffer on top of the recipient's amount
+    const record = await program.account.issuanceRecord.fetch(recordPda);
+    const bufferTonnes = INITIAL_MINT_AMOUNT * 0.2;
+    assert.equal(record.bufferAmount.toString(), new anchor.BN(bufferTonnes * (10 ** TOKEN_DECIMALS)).toString());
+    const issued = amountToMint.add(record.bufferAmount);
+
+    assert.equal(projectAccount.creditsIssued.toString(), issued.toString());
+    assert.equal(projectAccount.tokensMinted.toString(), issued.toString());
+    assert.equal(projectAccount.exportTokensIssued.toString(), issued.toString());
+    const batch = await program.account.issuanceBatch.fetch(issuanceBatchPda);
+    assert.equal(batch.tokensMinted.toString(), issued.toString());
+
+    const vault = await getAccount(provider.connection, bufferVaultPda, undefined, TOKEN_2022_PROGRAM_ID);
+    assert.equal(vault.amount.toString(), record.bufferAmount.toString());
+
+    assert.equal(record.serialStart.toNumber(), 1);
+    assert.equal(record.serialEnd.toNumber(), INITIAL_MINT_AMOUNT + bufferTonnes);
+    assert.equal(record.serialPrefix, mintInfo.serialPrefix);
+    console.log(`Serials ${record.serialPrefix}-${record.serialStart}..${record.serialEnd}`);
     // Note: token account may have accumulated balance from previous test runs
     console.log("Token account balance:", tokenAccountInfo.amount.toString());
     console.log("Expected amount:", amountToMint.toString());
@@ -329,13 +1860,87 @@ describe("blue-carbon-registry", () => {
     console.log("✅ Credits minted successfully");
   });
 
+  it("Keeps buffer credits locked during the permanence period", async () => {
+    try {
+      await program.methods
+        .setProjectBufferRate(6000)
+        .accounts({ project: projectPda, registry: registryPda, admin: projectOwner.publicKey } as any)
+        .signers([projectOwner])
+        .rpc();
+      assert.fail("Buffer rate above the maximum should fail");
+    } catch (e) {
+      assert.include(e.toString(), "InvalidBufferRate");
+    }
+
+    // Each issuance's buffer carries its own permanence period
+    const mintInfo = await program.account.creditMintInfo.fetch(creditMintInfoPda);
+    const recordPda = issuanceRecordPda(mintInfo.issuances - 1);
+    const record = await program.account.issuanceRecord.fetch(recordPda);
+    assert.ok(record.permanenceEndsAt.gt(record.issuedAt));
+    assert.equal(record.bufferHeld.toString(), record.bufferAmount.toString());
+
+    try {
+      await program.methods
+        .releaseBufferCredits()
+        .accounts({
+          project: projectPda,
+          registry: registryPda,
+          creditMintInfo: creditMintInfoPda,
+          issuanceRecord: recordPda,
+          creditMint,
+          bufferVault: bufferVaultPda,
+          ownerTokenAccount: projectTokenAccount,
+          ownerJurisdiction: null,
+          owner: projectOwner.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        } as any)
+        .signers([projectOwner])
+        .rpc();
+      assert.fail("Buffer release should fail before the permanence period ends");
+    } catch (e) {
+      assert.include(e.toString(), "PermanencePeriodActive");
+      console.log("✅ Buffer credits locked until permanence period ends");
+    }
+  });
+
+  it("Cancels reversed buffer credits from the issuance's own buffer serials", async () => {
+    const recordPda = issuanceRecordPda(0);
+    const before = await program.account.issuanceRecord.fetch(recordPda);
+    const cancelTonnes = 10;
+    const cancelAmount = new anchor.BN(cancelTonnes * (10 ** TOKEN_DECIMALS));
+
+    await program.methods
+      .cancelBufferCredits(cancelAmount, "QmReversalEvidence")
+      .accounts({
+        registry: registryPda,
+        creditMintInfo: creditMintInfoPda,
+        issuanceRecord: recordPda,
+        creditMint,
+        bufferVault: bufferVaultPda,
+        admin: projectOwner.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      } as any)
+      .signers([projectOwner])
+      .rpc();
+
+    // Cancellation takes serials down from the end of the issuance's range, leaving delivered serials alone
+    const record = await program.account.issuanceRecord.fetch(recordPda);
+    assert.equal(record.bufferCancelled.toString(), cancelAmount.toString());
+    assert.equal(record.bufferHeld.toString(), before.bufferHeld.sub(cancelAmount).toString());
+    assert.equal(record.serialsRetired.toNumber(), before.serialsRetired.toNumber());
+    const vault = await getAccount(provider.connection, bufferVaultPda, undefined, TOKEN_2022_PROGRAM_ID);
+    assert.equal(vault.amount.toString(), record.bufferHeld.toString());
+
+    console.log(`✅ Serials ${record.serialPrefix}-${record.serialEnd.toNumber() - cancelTonnes + 1}..${record.serialEnd} cancelled`);
+  });
+
   it("Transfers credits successfully", async () => {
     // Create token account for investor (owned by separate investor wallet)
     investorTokenAccount = (
       await getOrCreateAssociatedTokenAccount(
         provider.connection,
         projectOwner,
-        tokenMint,
+        creditMint,
         investorWallet.publicKey,
         false,
         undefined,
@@ -354,8 +1959,11 @@ describe("blue-carbon-registry", () => {
       .accounts({
         fromAccount: projectTokenAccount,
         toAccount: investorTokenAccount,
-        mint: tokenMint,
+        mint: creditMint,
         fromAuthority: projectOwner.publicKey, // Project owner is the authority
+        registry: registryPda,
+        creditMintInfo: creditMintInfoPda,
+        recipientJurisdiction: null,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
       } as any)
       .signers([projectOwner])
@@ -402,13 +2010,21 @@ describe("blue-carbon-registry", () => {
       ASSOCIATED_TOKEN_PROGRAM_ID
     );
 
+    const [retirementRecordPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("retirement_record"), certificateMintPda.toBuffer()],
+      program.programId
+    );
+
     const tx = await program.methods
       .retireCredits(amountToRetire, retirementId)
       .accounts({
-        creditMint: tokenMint,
+        creditMint: creditMint,
+        creditMintInfo: creditMintInfoPda,
+        issuanceRecord: issuanceRecordPda(0),
         userTokenAccount: investorTokenAccount,
         owner: investorWallet.publicKey,
         certificateMint: certificateMintPda,
+        retirementRecord: retirementRecordPda,
         certificateTokenAccount: certificateTokenAccount,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
         token2022Program: TOKEN_2022_PROGRAM_ID,
@@ -426,6 +2042,15 @@ describe("blue-carbon-registry", () => {
     assert.equal(certAccount.amount.toString(), "1");
     // Should check mint info for decimals=0 and non-transferable extension if possible, but simplest is amount check.
 
+    // Retirement cancels the issuance's next delivered serials in order, never its buffer serials
+    const retirement = await program.account.retirementRecord.fetch(retirementRecordPda);
+    assert.equal(retirement.serialStart.toNumber(), 1);
+    assert.equal(retirement.serialEnd.toNumber(), RETIREMENT_AMOUNT);
+    const issuance = await program.account.issuanceRecord.fetch(issuanceRecordPda(0));
+    assert.equal(issuance.serialsRetired.toNumber(), RETIREMENT_AMOUNT);
+    const bufferStart = issuance.serialEnd.toNumber() - issuance.bufferAmount.toNumber() / (10 ** TOKEN_DECIMALS) + 1;
+    assert.isBelow(retirement.serialEnd.toNumber(), bufferStart);
+
     // Verify Burn (Credits Removed)
     const creditAccount = await getAccount(provider.connection, investorTokenAccount, undefined, TOKEN_2022_PROGRAM_ID);
     // Previous tests transferred 200, so after retiring 100, should have 100.
@@ -493,7 +2118,7 @@ describe("blue-carbon-registry", () => {
   it("Initializes Liquidity Pool", async () => {
     // Derive PDAs
     [poolPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("liquidity_pool"), tokenMint.toBuffer(), quoteMint.toBuffer()],
+      [Buffer.from("liquidity_pool"), creditMint.toBuffer(), quoteMint.toBuffer()],
       program.programId
     );
 
@@ -516,7 +2141,9 @@ describe("blue-carbon-registry", () => {
       .accounts({
         pool: poolPda,
         lpMint: lpMintPda,
-        creditMint: tokenMint,
+        creditMint: creditMint,
+        creditMintInfo: creditMintInfoPda,
+        registry: registryPda,
         quoteMint: quoteMint,
         creditVault: creditVaultPda,
         quoteVault: quoteVaultPda,
@@ -557,10 +2184,12 @@ describe("blue-carbon-registry", () => {
         lpMint: lpMintPda,
         creditVault: creditVaultPda,
         quoteVault: quoteVaultPda,
-        creditMint: tokenMint,
+        creditMint: creditMint,
         quoteMint: quoteMint,
         userCreditAccount: projectTokenAccount,
         userQuoteAccount: quoteTokenAccount,
+        creditMintInfo: creditMintInfoPda,
+        recipientJurisdiction: null,
         userLpAccount: userLpAccount,
         provider: projectOwner.publicKey,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
@@ -595,10 +2224,12 @@ describe("blue-carbon-registry", () => {
       pool: poolPda,
       creditVault: creditVaultPda,
       quoteVault: quoteVaultPda,
-      creditMint: tokenMint,
+      creditMint: creditMint,
       quoteMint: quoteMint,
       userSourceAccount: projectTokenAccount, // Credits
       userDestinationAccount: quoteTokenAccount, // Quote
+      creditMintInfo: creditMintInfoPda,
+      recipientJurisdiction: null,
       user: projectOwner.publicKey,
       tokenProgram: TOKEN_2022_PROGRAM_ID,
     } as any)
@@ -620,10 +2251,12 @@ describe("blue-carbon-registry", () => {
         lpMint: lpMintPda,
         creditVault: creditVaultPda,
         quoteVault: quoteVaultPda,
-        creditMint: tokenMint,
+        creditMint: creditMint,
         quoteMint: quoteMint,
         userCreditAccount: projectTokenAccount,
         userQuoteAccount: quoteTokenAccount,
+        creditMintInfo: creditMintInfoPda,
+        recipientJurisdiction: null,
         userLpAccount: userLpAccount,
         provider: projectOwner.publicKey,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
@@ -651,7 +2284,7 @@ describe("blue-carbon-registry", () => {
       return (await getOrCreateAssociatedTokenAccount(
         provider.connection,
         projectOwner,
-        tokenMint,
+        creditMint,
         recipient.publicKey,
         false,
         undefined,
@@ -660,13 +2293,21 @@ describe("blue-carbon-registry", () => {
       )).address;
     }));
 
+    const mintInfo = await program.account.creditMintInfo.fetch(creditMintInfoPda);
+
     const tx = await program.methods.batchMintCredits(amounts)
       .accounts({
         project: projectPda,
+        issuanceBatch: issuanceBatchPda,
         registry: registryPda,
-        carbonTokenMint: tokenMint,
+        registryConfig: registryConfigPda,
+        carbonTokenMint: creditMint,
+        creditMintInfo: creditMintInfoPda,
+        issuanceRecord: issuanceRecordPda(mintInfo.issuances),
+        bufferVault: bufferVaultPda,
         owner: projectOwner.publicKey,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
       } as any)
       .remainingAccounts(recipientTokenAccounts.map(pubkey => ({
         pubkey,
@@ -685,6 +2326,10 @@ describe("blue-carbon-registry", () => {
       console.log(`Recipient ${i} balance verified: ${info.amount.toString()}`);
     }
 
+    const record = await program.account.issuanceRecord.fetch(issuanceRecordPda(mintInfo.issuances));
+    assert.equal(record.serialStart.toNumber(), mintInfo.serialsIssued.toNumber() + 1);
+    assert.equal(record.serialEnd.toNumber(), mintInfo.serialsIssued.toNumber() + 60 + 12);
+
 
     console.log("✅ Batch Mint Successful");
   });
@@ -711,7 +2356,7 @@ describe("blue-carbon-registry", () => {
 
     const listingData = {
       projectId: projectId,
-      vintageYear: 2024,
+      vintageYear: new Date().getFullYear(),
       quantityAvailable: new anchor.BN(50_000_000), // 50 credits
       pricePerTon: new anchor.BN(5_000_000), // 5 USDC
       certificationStandards: ["Gold Standard"],
@@ -726,7 +2371,9 @@ describe("blue-carbon-registry", () => {
         project: projectPda,
         seller: projectOwner.publicKey,
         sellerCreditAccount: projectTokenAccount,
-        creditMint: tokenMint,
+        creditMint: creditMint,
+        creditMintInfo: creditMintInfoPda,
+        registry: registryPda,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         rent: anchor.web3.SYSVAR_RENT_PUBKEY,
@@ -780,8 +2427,10 @@ describe("blue-carbon-registry", () => {
         buyer: investorWallet.publicKey,
         buyerPaymentAccount: investorQuoteAccount.address,
         buyerCreditAccount: investorTokenAccount, // Existing account from transfer test
-        creditMint: tokenMint,
+        creditMint: creditMint,
         currencyMint: quoteMint,
+        creditMintInfo: creditMintInfoPda,
+        buyerJurisdiction: null,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
       } as any)
       .signers([investorWallet])
@@ -809,7 +2458,7 @@ describe("blue-carbon-registry", () => {
         listingVault: listingVaultPda,
         seller: projectOwner.publicKey,
         sellerCreditAccount: projectTokenAccount,
-        creditMint: tokenMint, // Requires Mint now for checked transfer
+        creditMint: creditMint, // Requires Mint now for checked transfer
         tokenProgram: TOKEN_2022_PROGRAM_ID,
       } as any)
       .signers([projectOwner])
@@ -833,4 +2482,204 @@ describe("blue-carbon-registry", () => {
     console.log("✅ Listing Cancelled");
   });
 
-});
\ No newline at end of file
+
+  it("Keeps domestic-only credits away from foreign buyers", async () => {
+    const vintage = new Date().getFullYear();
+    const [domesticMint] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("credit_mint"),
+        projectPda.toBuffer(),
+        generationSeed(),
+        new anchor.BN(vintage).toArrayLike(Buffer, "le", 2),
+        Buffer.from([0]), // domestic only
+      ],
+      program.programId
+    );
+    const [domesticInfoPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("credit_mint_info"), domesticMint.toBuffer()],
+      program.programId
+    );
+    const [domesticBufferVault] = PublicKey.findProgramAddressSync(
+      [Buffer.from("buffer_vault"), domesticMint.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .createCreditMint(vintage, "VM0033", false)
+      .accounts({
+        project: projectPda,
+        registry: registryPda,
+        registryConfig: registryConfigPda,
+        creditMint: domesticMint,
+        creditMintInfo: domesticInfoPda,
+        owner: projectOwner.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([projectOwner])
+      .rpc();
+
+    const ownerDomesticAccount = (
+      await getOrCreateAssociatedTokenAccount(
+        provider.connection, projectOwner, domesticMint, projectOwner.publicKey,
+        false, undefined, undefined, TOKEN_2022_PROGRAM_ID
+      )
+    ).address;
+    const investorDomesticAccount = (
+      await getOrCreateAssociatedTokenAccount(
+        provider.connection, projectOwner, domesticMint, investorWallet.publicKey,
+        false, undefined, undefined, TOKEN_2022_PROGRAM_ID
+      )
+    ).address;
+
+    // Domestic-only token accounts start frozen until the registry thaws them
+    assert.isTrue((await getAccount(provider.connection, investorDomesticAccount, undefined, TOKEN_2022_PROGRAM_ID)).isFrozen);
+
+    const jurisdictionPda = (wallet: PublicKey) =>
+      PublicKey.findProgramAddressSync([Buffer.from("jurisdiction"), wallet.toBuffer()], program.programId)[0];
+    const attest = (wallet: PublicKey) =>
+      program.methods
+        .setParticipantJurisdiction(wallet, "IN")
+        .accounts({
+          jurisdiction: jurisdictionPda(wallet),
+          authority: projectOwner.publicKey,
+          userAccount: ownerUserAccount,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([projectOwner])
+        .rpc();
+    const mintDomestic = (jurisdiction: PublicKey | null) =>
+      program.methods
+        .mintVerifiedCredits(new anchor.BN(5_000_000))
+        .accounts({
+          project: projectPda,
+          issuanceBatch: issuanceBatchPda,
+          registry: registryPda,
+          registryConfig: registryConfigPda,
+          carbonTokenMint: domesticMint,
+          creditMintInfo: domesticInfoPda,
+          issuanceRecord: PublicKey.findProgramAddressSync(
+            [Buffer.from("issuance_record"), domesticMint.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 4)],
+            program.programId
+          )[0],
+          bufferVault: domesticBufferVault,
+          recipientTokenAccount: ownerDomesticAccount,
+          recipientJurisdiction: jurisdiction,
+          owner: projectOwner.publicKey,
+          recipient: projectOwner.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([projectOwner])
+        .rpc();
+
+    try {
+      await mintDomestic(null);
+      assert.fail("Domestic-only credits should not be minted to an unattested wallet");
+    } catch (e) {
+      assert.include(e.toString(), "DomesticOnlyCredits");
+    }
+
+    // Domestic issuance does not count against the export limit
+    const exportedBefore = (await program.account.project.fetch(projectPda)).exportTokensIssued;
+    await attest(projectOwner.publicKey);
+    await mintDomestic(jurisdictionPda(projectOwner.publicKey));
+    assert.isFalse((await getAccount(provider.connection, ownerDomesticAccount, undefined, TOKEN_2022_PROGRAM_ID)).isFrozen);
+    const exportedAfter = (await program.account.project.fetch(projectPda)).exportTokensIssued;
+    assert.equal(exportedAfter.toString(), exportedBefore.toString());
+
+    const listingData = {
+      projectId: projectId,
+      vintageYear: vintage,
+      quantityAvailable: new anchor.BN(5_000_000),
+      pricePerTon: new anchor.BN(5_000_000),
+      certificationStandards: ["Gold Standard"],
+      currencyMint: quoteMint,
+      expiryDate: new anchor.BN(Date.now() / 1000 + 86400),
+    };
+    await program.methods.createMarketplaceListing(projectId, listingData)
+      .accounts({
+        listing: listingPda,
+        listingVault: listingVaultPda,
+        project: projectPda,
+        seller: projectOwner.publicKey,
+        sellerCreditAccount: ownerDomesticAccount,
+        creditMint: domesticMint,
+        creditMintInfo: domesticInfoPda,
+        registry: registryPda,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      } as any)
+      .signers([projectOwner])
+      .rpc();
+
+    const investorQuoteAccount = (
+      await getOrCreateAssociatedTokenAccount(
+        provider.connection, projectOwner, quoteMint, investorWallet.publicKey,
+        false, undefined, undefined, TOKEN_2022_PROGRAM_ID
+      )
+    ).address;
+    const [investorJurisdiction] = PublicKey.findProgramAddressSync(
+      [Buffer.from("jurisdiction"), investorWallet.publicKey.toBuffer()],
+      program.programId
+    );
+    const buy = (jurisdiction: PublicKey | null) =>
+      program.methods.buyMarketplaceListing(new anchor.BN(2_000_000))
+        .accounts({
+          listing: listingPda,
+          listingVault: listingVaultPda,
+          sellerPaymentAccount: quoteTokenAccount,
+          buyer: investorWallet.publicKey,
+          buyerPaymentAccount: investorQuoteAccount,
+          buyerCreditAccount: investorDomesticAccount,
+          creditMint: domesticMint,
+          currencyMint: quoteMint,
+          creditMintInfo: domesticInfoPda,
+          buyerJurisdiction: jurisdiction,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        } as any)
+        .signers([investorWallet])
+        .rpc();
+
+    try {
+      await buy(null);
+      assert.fail("Foreign buyer should not receive domestic-only credits");
+    } catch (e) {
+      assert.include(e.toString(), "DomesticOnlyCredits");
+    }
+
+    // Outside the program, a plain Token-2022 transfer to the frozen account fails too
+    try {
+      await transferChecked(
+        provider.connection, projectOwner, ownerDomesticAccount, domesticMint, investorDomesticAccount,
+        projectOwner, 1_000_000, TOKEN_DECIMALS, [], undefined, TOKEN_2022_PROGRAM_ID
+      );
+      assert.fail("Frozen domestic-only account should not receive credits");
+    } catch (e) {
+      assert.include(e.toString(), "0x11"); // TokenError::AccountFrozen
+    }
+
+    // Once attested as resident in the host country the buyer's account is thawed and may purchase
+    await attest(investorWallet.publicKey);
+    await program.methods
+      .thawCreditAccount()
+      .accounts({
+        registry: registryPda,
+        creditMintInfo: domesticInfoPda,
+        creditMint: domesticMint,
+        tokenAccount: investorDomesticAccount,
+        jurisdiction: investorJurisdiction,
+        cranker: investorWallet.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      } as any)
+      .signers([investorWallet])
+      .rpc();
+    await buy(investorJurisdiction);
+
+    const investorCredits = await getAccount(provider.connection, investorDomesticAccount, undefined, TOKEN_2022_PROGRAM_ID);
+    assert.equal(investorCredits.amount.toString(), "2000000");
+    console.log("✅ Domestic-only credits restricted to resident buyers");
+  });
+});
@@ -69,7 +69,7 @@ export default function RegisterProjectPage() {
 
         setLoading(true);
         try {
-            const [projectPda] = getProjectPda(publicKey, formData.icmRegistryId);
+            const [projectPda] = getProjectPda(formData.icmRegistryId);
             const [registryPda] = getRegistryPda();
             const [doubleCountingRegistryPda] = getDoubleCountingRegistryPda();
             const [userAccountPda] = getUserAccountPda(publicKey);
@@ -84,13 +84,15 @@ export default function RegisterProjectPage() {
                 carbonTonsEstimated: new anchor.BN(parseInt(formData.carbonTonsEstimated) || 0),
                 projectSector: { [formData.projectSector]: {} },
                 location: {
-                    latitude: parseFloat(formData.latitude) || 0,
-                    longitude: parseFloat(formData.longitude) || 0,
+                    // On-chain coordinates are micro-degrees
+                    latitude: Math.round((parseFloat(formData.latitude) || 0) * 1_000_000),
+                    longitude: Math.round((parseFloat(formData.longitude) || 0) * 1_000_000),
                     polygonCoordinates: [],
                     countryCode: formData.countryCode,
                     regionName: formData.regionName,
                 },
-                areaHectares: parseFloat(formData.areaHectares) || 0,
+                // On-chain area is milli-hectares
+                areaHectares: new anchor.BN(Math.round((parseFloat(formData.areaHectares) || 0) * 1_000)),
                 establishmentDate: new anchor.BN(Math.floor(Date.now() / 1000)),
                 vintageYear: parseInt(formData.vintageYear.toString()),
                 pricePerTon: new anchor.BN(parseInt(formData.pricePerTon) * 1_000_000 || 0),
@@ -27,7 +27,7 @@ export function useProject(owner: PublicKey | null, projectId: string | null) {
         queryFn: async (): Promise<Project | null> => {
             if (!program || !owner || !projectId) return null;
 
-            const [projectPda] = getProjectPda(owner, projectId);
+            const [projectPda] = getProjectPda(projectId);
 
             try {
                 // eslint-disable-next-line @typescript-eslint/no-explicit-any
@@ -44,13 +44,15 @@ export function useProject(owner: PublicKey | null, projectId: string | null) {
                     bump: account.bump,
                     projectSector: parseProjectSector(account.projectSector),
                     location: {
-                        latitude: account.location.latitude,
-                        longitude: account.location.longitude,
-                        polygonCoordinates: account.location.polygonCoordinates,
+                        latitude: account.location.latitude / 1_000_000,
+                        longitude: account.location.longitude / 1_000_000,
+                        polygonCoordinates: account.location.polygonCoordinates.map(
+                            ([lat, lng]: [number, number]) => [lat / 1_000_000, lng / 1_000_000]
+                        ),
                         countryCode: account.location.countryCode,
                         regionName: account.location.regionName,
                     },
-                    areaHectares: account.areaHectares,
+                    areaHectares: account.areaHectares.toNumber() / 1_000,
                     establishmentDate: account.establishmentDate.toNumber(),
                     compliance: {
                         cctsRegistryId: account.compliance.cctsRegistryId,
@@ -44,11 +44,11 @@ export function getUserAccountPda(userWallet: PublicKey): [PublicKey, number] {
 }
 
 /**
- * Derive Project PDA
+ * Derive Project PDA (keyed by ICM registry ID, independent of the owner)
  */
-export function getProjectPda(owner: PublicKey, projectId: string): [PublicKey, number] {
+export function getProjectPda(projectId: string): [PublicKey, number] {
     return PublicKey.findProgramAddressSync(
-        [Buffer.from("project"), owner.toBuffer(), Buffer.from(projectId)],
+        [Buffer.from("project"), Buffer.from(projectId)],
         programId
     );
 }